@@ -3,7 +3,7 @@ use nalgebra::DMatrix;
 use num_traits::Num;
 use std::fmt::Debug;
 
-impl<T> SimplicialComplex<T> where T: Ord + Copy {
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
     /// Compute the k-th boundary map of a simplicial complex.
     pub fn boundary<U: 'static + Num + Clone + Debug>(&self, k: usize) -> DMatrix<U> {
         if k == 0 {
@@ -15,18 +15,38 @@ impl<T> SimplicialComplex<T> where T: Ord + Copy {
         let n = ck.len();
         let m = ck1.len();
         let i = ck.into_iter()
-            .map(|s| ck1.iter()
-                .map(move |t| s.boundary_coeff(t)))
-                .flatten();
+            .flat_map(|s| ck1.iter()
+                .map(move |t| s.boundary_coeff(t)));
         DMatrix::from_iterator(m, n, i)
     }
 
+    /// Compute the k-th boundary map of the augmented chain complex. This is the same as
+    /// `boundary` except for k = 0, where the augmentation sends every vertex to 1 in the ground
+    /// ring, so it is a 1 by n matrix of ones.
+    pub fn augmented_boundary<U: 'static + Num + Clone + Debug>(&self, k: usize) -> DMatrix<U> {
+        if k == 0 {
+            let n = self.vertices.len();
+            return DMatrix::from_element(1, n, U::one());
+        }
+        self.boundary(k)
+    }
+
     pub fn betti(&self, k: usize) -> usize {
         let dk = self.boundary::<f64>(k);
         let dk1 = self.boundary::<f64>(k + 1);
-        let rk = if dk.nrows() * dk.ncols() == 0 { 0 } else { dk.rank(1e-5) };
-        let rk1 = if dk1.nrows() * dk1.ncols() == 0 { 0 } else { dk1.rank(1e-5) };
-        dk.ncols() - (rk + rk1)
+        dk.ncols() - (rank(&dk) + rank(&dk1))
+    }
+
+    /// The k-th reduced Betti number, ie. the rank of the k-th homology of the augmented chain
+    /// complex. This is the same as `betti` except in dimension 0, where it counts the number of
+    /// components minus 1. A non-empty complex is acyclic iff all of these are zero.
+    pub fn reduced_betti(&self, k: usize) -> usize {
+        let dk = self.augmented_boundary::<f64>(k);
+        let dk1 = self.augmented_boundary::<f64>(k + 1);
+        dk.ncols() - (rank(&dk) + rank(&dk1))
     }
+}
 
+pub(crate) fn rank(d: &DMatrix<f64>) -> usize {
+    if d.nrows() * d.ncols() == 0 { 0 } else { d.rank(1e-5) }
 }
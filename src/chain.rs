@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::iter::FromIterator;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use nalgebra::DMatrix;
+use num_traits::Num;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::linear_algebra::coefficients::Coefficients;
+use super::linear_algebra::integer::invariant_factors;
+
+/// A chain, ie. a formal linear combination of simplices such as 2[0, 1] - [1, 2], with
+/// coefficients in R. Only the simplices with non-zero coefficients are stored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chain<T, R> {
+    terms: BTreeMap<Simplex<T>, R>,
+}
+
+impl<T, R> Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    pub fn zero() -> Self {
+        Chain { terms: BTreeMap::new() }
+    }
+
+    /// The chain consisting of a single simplex with coefficient 1.
+    pub fn simplex(s: Simplex<T>) -> Self {
+        Chain::term(s, R::one())
+    }
+
+    /// The chain c s.
+    pub fn term(s: Simplex<T>, c: R) -> Self {
+        let mut chain = Chain::zero();
+        chain.add_term(s, c);
+        chain
+    }
+
+    /// The coefficient of a simplex, which is 0 if it does not appear.
+    pub fn coefficient(&self, s: &Simplex<T>) -> R {
+        self.terms.get(s).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// The simplices with non-zero coefficients, along with their coefficients.
+    pub fn terms(&self) -> impl Iterator<Item = (&Simplex<T>, &R)> {
+        self.terms.iter()
+    }
+
+    /// The simplices with non-zero coefficients.
+    pub fn support(&self) -> BTreeSet<Simplex<T>> {
+        self.terms.keys().cloned().collect()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Add c s to the chain.
+    pub fn add_term(&mut self, s: Simplex<T>, c: R) {
+        let sum = self.coefficient(&s) + c;
+        if sum.is_zero() {
+            self.terms.remove(&s);
+        } else {
+            self.terms.insert(s, sum);
+        }
+    }
+
+    /// The boundary of the chain, extended linearly from the boundary of a simplex, which is the
+    /// sum of its faces with the signs given by `Simplex::boundary_coeff`.
+    pub fn boundary(&self) -> Self {
+        let mut chain = Chain::zero();
+        for (s, c) in &self.terms {
+            for t in s.faces() {
+                let sign: R = s.boundary_coeff(&t);
+                chain.add_term(t, c.clone() * sign);
+            }
+        }
+        chain
+    }
+
+    pub fn is_cycle(&self) -> bool {
+        self.boundary().is_zero()
+    }
+}
+
+impl<T, R> Chain<T, R> where T: Ord + Clone, R: Num + Clone + Into<i64> {
+    /// Whether the chain is the boundary of a chain in the given complex, with the given
+    /// coefficients. Each simplex of the chain must be in the complex.
+    ///
+    /// Over a field, a k-chain c is a boundary iff adding it as a column to d_{k+1} does not
+    /// change the rank. Over the integers, the invariant factors must not change either, as
+    /// d_{k+1} x = c has an integer solution iff d_{k+1} and (d_{k+1} | c) have the same
+    /// invariant factors.
+    pub fn is_boundary(&self, complex: &SimplicialComplex<T>, coefficients: Coefficients) -> bool {
+        if self.terms.keys().any(|s| !complex.simplices.contains(s)) {
+            return false;
+        }
+        // A chain is a boundary iff each of its homogeneous parts is.
+        let dims: BTreeSet<usize> = self.terms.keys().map(|s| s.dim()).collect();
+        dims.into_iter().all(|k| {
+            let d = complex.boundary::<i64>(k + 1);
+            let mut m = DMatrix::zeros(d.nrows(), d.ncols() + 1);
+            m.slice_mut((0, 0), d.shape()).copy_from(&d);
+            for (i, s) in complex.dim_simplices(k).iter().enumerate() {
+                m[(i, d.ncols())] = self.coefficient(s).into();
+            }
+            match coefficients {
+                Coefficients::Integers => invariant_factors(&d) == invariant_factors(&m),
+                _ => coefficients.rank(&d) == coefficients.rank(&m),
+            }
+        })
+    }
+}
+
+impl<T, R> FromIterator<(Simplex<T>, R)> for Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    fn from_iter<I: IntoIterator<Item = (Simplex<T>, R)>>(iter: I) -> Self {
+        let mut chain = Chain::zero();
+        for (s, c) in iter {
+            chain.add_term(s, c);
+        }
+        chain
+    }
+}
+
+impl<T, R> Add for Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (s, c) in other.terms {
+            self.add_term(s, c);
+        }
+        self
+    }
+}
+
+impl<T, R> Neg for Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self * (R::zero() - R::one())
+    }
+}
+
+impl<T, R> Sub for Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T, R> Mul<R> for Chain<T, R> where T: Ord + Clone, R: Num + Clone {
+    type Output = Self;
+
+    fn mul(self, c: R) -> Self {
+        self.terms.into_iter()
+            .map(|(s, x)| (s, x * c.clone()))
+            .collect()
+    }
+}
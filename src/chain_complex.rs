@@ -0,0 +1,171 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use std::collections::BTreeMap;
+
+use nalgebra::DMatrix;
+
+use super::simplicial_complex::SimplicialComplex;
+use super::linear_algebra::coefficients::Coefficients;
+use super::linear_algebra::integer::invariant_factors;
+use super::linear_algebra::sparse::SparseMatrix;
+
+/// A chain complex of finitely generated free abelian groups
+///
+/// ```text
+/// 0 <- C_0 <- C_1 <- ... <- C_n <- 0
+/// ```
+///
+/// where the k-th differential d_k: C_k -> C_{k-1} is an integer matrix with respect to some
+/// fixed bases. As for `SimplicialComplex::boundary`, d_0 is the zero map to the zero group, so
+/// it is a 0 by rank(C_0) matrix. The differentials are stored as sparse matrices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainComplex {
+    differentials: Vec<SparseMatrix>,
+}
+
+/// The reasons a list of matrices can fail to be a chain complex.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChainComplexError {
+    /// d_0 is not a map to the zero group.
+    NonZeroTarget,
+    /// d_k is not composable with d_{k-1}.
+    ShapeMismatch(usize),
+    /// d_{k-1} d_k is not zero.
+    NotAComplex(usize),
+    /// An entry of d_{k-1} d_k does not fit in an i64.
+    Overflow(usize),
+}
+
+impl Display for ChainComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainComplexError::NonZeroTarget => write!(f, "d_0 must map to the zero group"),
+            ChainComplexError::ShapeMismatch(k) => write!(f, "d_{} is not composable with d_{}", k, k - 1),
+            ChainComplexError::NotAComplex(k) => write!(f, "d_{} d_{} is not zero", k - 1, k),
+            ChainComplexError::Overflow(k) => write!(f, "d_{} d_{} overflows", k - 1, k),
+        }
+    }
+}
+
+impl Error for ChainComplexError {}
+
+/// A finitely generated abelian group Z^rank ⊕ Z/t_1 ⊕ ... ⊕ Z/t_m, where each t_i > 1 divides
+/// the next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Homology {
+    pub rank: usize,
+    pub torsion: Vec<i64>,
+}
+
+impl ChainComplex {
+    /// Construct a chain complex from its differentials d_0, ..., d_n, given either as dense or
+    /// sparse matrices, checking that consecutive differentials compose to zero.
+    pub fn new<M: Into<SparseMatrix>>(differentials: Vec<M>) -> Result<Self, ChainComplexError> {
+        let differentials: Vec<SparseMatrix> = differentials.into_iter().map(Into::into).collect();
+        if differentials.first().is_some_and(|d| d.nrows() != 0) {
+            return Err(ChainComplexError::NonZeroTarget);
+        }
+        for k in 1..differentials.len() {
+            let (d, d1) = (&differentials[k - 1], &differentials[k]);
+            if d.ncols() != d1.nrows() {
+                return Err(ChainComplexError::ShapeMismatch(k));
+            }
+            match d.checked_mul(d1) {
+                None => return Err(ChainComplexError::Overflow(k)),
+                Some(dd) if !dd.is_zero() => return Err(ChainComplexError::NotAComplex(k)),
+                _ => {},
+            }
+        }
+        Ok(ChainComplex { differentials })
+    }
+
+    /// The largest k for which C_k is given, which may be 0.
+    pub fn dim(&self) -> usize {
+        self.differentials.len().saturating_sub(1)
+    }
+
+    /// The rank of C_k.
+    pub fn rank(&self, k: usize) -> usize {
+        self.differentials.get(k).map_or(0, |d| d.ncols())
+    }
+
+    /// The k-th differential d_k: C_k -> C_{k-1}, which is zero outside the given range, as a
+    /// dense matrix to be reduced.
+    pub fn differential(&self, k: usize) -> DMatrix<i64> {
+        match self.differentials.get(k) {
+            Some(d) => d.to_dense(),
+            None => DMatrix::zeros(if k == 0 { 0 } else { self.rank(k - 1) }, 0),
+        }
+    }
+
+    /// The differentials d_0, ..., d_n as they are stored.
+    pub fn differentials(&self) -> &[SparseMatrix] {
+        &self.differentials
+    }
+
+    /// The shifted complex ΣC, which has (ΣC)_k = C_{k-1} and differential -d_{k-1}, so that
+    /// H_k(ΣC) = H_{k-1}(C).
+    pub fn shift(&self) -> ChainComplex {
+        let mut differentials = vec![SparseMatrix::new(0, vec![])];
+        differentials.extend(self.differentials.iter().map(|d| -d));
+        ChainComplex { differentials }
+    }
+
+    /// The k-th Betti number with the given coefficients.
+    pub fn betti(&self, k: usize, coefficients: Coefficients) -> usize {
+        self.rank(k)
+            - coefficients.rank(&self.differential(k))
+            - coefficients.rank(&self.differential(k + 1))
+    }
+
+    /// The k-th homology group with the given coefficients. Over a field, there is no torsion.
+    /// Over the integers, the torsion is given by the invariant factors of d_{k+1} which are not 1.
+    pub fn homology(&self, k: usize, coefficients: Coefficients) -> Homology {
+        match coefficients {
+            Coefficients::Integers => {
+                let factors = invariant_factors(&self.differential(k + 1));
+                Homology {
+                    rank: self.rank(k) - coefficients.rank(&self.differential(k)) - factors.len(),
+                    torsion: factors.into_iter().filter(|&t| t != 1).collect(),
+                }
+            },
+            _ => Homology {
+                rank: self.betti(k, coefficients),
+                torsion: vec![],
+            },
+        }
+    }
+}
+
+impl<T> From<&SimplicialComplex<T>> for ChainComplex where T: Ord + Clone {
+    /// The simplicial chain complex, with the differentials given by `SimplicialComplex::boundary`.
+    /// The columns are built directly from the faces of each simplex, without a dense matrix.
+    fn from(k: &SimplicialComplex<T>) -> Self {
+        let simplices: Vec<_> = (0..=k.dim()).map(|i| k.dim_simplices(i)).collect();
+        let differentials = (0..=k.dim())
+            .map(|i| {
+                if i == 0 {
+                    return SparseMatrix::new(0, vec![vec![]; simplices[0].len()]);
+                }
+                let index: BTreeMap<_, _> = simplices[i - 1].iter()
+                    .enumerate()
+                    .map(|(j, s)| (s, j))
+                    .collect();
+                SparseMatrix::new(simplices[i - 1].len(), simplices[i].iter()
+                    .map(|s| s.faces()
+                        .map(|t| (index[&t], s.boundary_coeff::<i64>(&t)))
+                        .collect())
+                    .collect())
+            })
+            .collect();
+        ChainComplex { differentials }
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The k-th homology group with the given coefficients.
+    pub fn homology(&self, k: usize, coefficients: Coefficients) -> Homology {
+        ChainComplex::from(self).homology(k, coefficients)
+    }
+}
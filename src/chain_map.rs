@@ -0,0 +1,261 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use nalgebra::DMatrix;
+
+use super::chain_complex::ChainComplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::simplicial_map::SimplicialMap;
+use super::linear_algebra::coefficients::Coefficients;
+
+/// A chain map f: C -> D, given by matrices f_k: C_k -> D_k which commute with the
+/// differentials, ie. d_k f_k = f_{k-1} d_k.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainMap {
+    source: ChainComplex,
+    target: ChainComplex,
+    maps: Vec<DMatrix<i64>>,
+}
+
+/// The reasons a list of matrices can fail to be a chain map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChainMapError {
+    /// f_k is not a map from C_k to D_k.
+    ShapeMismatch(usize),
+    /// f_k does not commute with the differentials.
+    NotAChainMap(usize),
+}
+
+impl Display for ChainMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainMapError::ShapeMismatch(k) => write!(f, "f_{} has the wrong shape", k),
+            ChainMapError::NotAChainMap(k) => write!(f, "f_{} does not commute with the differentials", k),
+        }
+    }
+}
+
+impl Error for ChainMapError {}
+
+/// The induced map on the k-th homology over a field, described by its rank.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InducedMap {
+    /// The rank of the homology of the source.
+    pub source: usize,
+    /// The rank of the homology of the target.
+    pub target: usize,
+    /// The rank of the image.
+    pub rank: usize,
+    /// The rank of the kernel.
+    pub kernel: usize,
+    /// The rank of the cokernel.
+    pub cokernel: usize,
+}
+
+impl ChainMap {
+    /// Construct a chain map from the matrices f_0, f_1, ..., checking that they commute with the
+    /// differentials. Any f_k which is not given is taken to be zero.
+    pub fn new(source: ChainComplex, target: ChainComplex, maps: Vec<DMatrix<i64>>) -> Result<Self, ChainMapError> {
+        let f = ChainMap { source, target, maps };
+        for k in 0..=f.dim() {
+            if f.map(k).shape() != (f.target.rank(k), f.source.rank(k)) {
+                return Err(ChainMapError::ShapeMismatch(k));
+            }
+        }
+        for k in 1..=f.dim() {
+            if f.target.differential(k) * f.map(k) != f.map(k - 1) * f.source.differential(k) {
+                return Err(ChainMapError::NotAChainMap(k));
+            }
+        }
+        Ok(f)
+    }
+
+    pub fn source(&self) -> &ChainComplex {
+        &self.source
+    }
+
+    pub fn target(&self) -> &ChainComplex {
+        &self.target
+    }
+
+    /// The largest k for which f_k can be non-zero.
+    pub fn dim(&self) -> usize {
+        self.source.dim().max(self.target.dim()).max(self.maps.len().saturating_sub(1))
+    }
+
+    /// The k-th map f_k: C_k -> D_k.
+    pub fn map(&self, k: usize) -> DMatrix<i64> {
+        match self.maps.get(k) {
+            Some(f) => f.clone(),
+            None => DMatrix::zeros(self.target.rank(k), self.source.rank(k)),
+        }
+    }
+
+    /// The induced map on the k-th homology with the given coefficients. Returns None for
+    /// `Coefficients::Integers`, as the ranks would miss the map on the torsion, so use
+    /// `Coefficients::Rationals` for the free part.
+    pub fn induced_map(&self, k: usize, coefficients: Coefficients) -> Option<InducedMap> {
+        if coefficients == Coefficients::Integers {
+            return None;
+        }
+        let d = self.source.differential(k);
+        let e = self.target.differential(k);
+        let d1 = self.source.differential(k + 1);
+        let e1 = self.target.differential(k + 1);
+        let f = self.map(k);
+        let rank = |m: &DMatrix<i64>| coefficients.rank(m);
+        // The image of [[d, 0], [f, e1]] projects onto the image of d, with kernel f(Z_k) + B_k,
+        // so its rank is rank(d) + dim(f(Z_k) + B_k), and the rank of f_* is
+        // dim(f(Z_k) + B_k) - rank(e1).
+        let mut m = DMatrix::zeros(d.nrows() + f.nrows(), d.ncols() + e1.ncols());
+        m.slice_mut((0, 0), d.shape()).copy_from(&d);
+        m.slice_mut((d.nrows(), 0), f.shape()).copy_from(&f);
+        m.slice_mut((d.nrows(), d.ncols()), e1.shape()).copy_from(&e1);
+        let (rd, re, rd1, re1) = (rank(&d), rank(&e), rank(&d1), rank(&e1));
+        let image = rank(&m) - rd - re1;
+        let source = d.ncols() - rd - rd1;
+        let target = e.ncols() - re - re1;
+        Some(InducedMap {
+            source,
+            target,
+            rank: image,
+            kernel: source - image,
+            cokernel: target - image,
+        })
+    }
+
+    /// The mapping cone of f: C -> D, which has cone_k = C_{k-1} ⊕ D_k and differential
+    ///
+    /// ```text
+    /// ( -d_{k-1}    0  )
+    /// (  f_{k-1}   d_k )
+    /// ```
+    ///
+    /// Its homology fits into the long exact sequence
+    /// ... -> H_k(C) -> H_k(D) -> H_k(cone) -> H_{k-1}(C) -> ...
+    pub fn mapping_cone(&self) -> ChainComplex {
+        let rank = |k: usize| if k == 0 { 0 } else { self.source.rank(k - 1) };
+        let differentials = (0..=self.dim() + 1)
+            .map(|k| {
+                let e = self.target.differential(k);
+                if k == 0 {
+                    return e;
+                }
+                let d = -self.source.differential(k - 1);
+                let f = self.map(k - 1);
+                let mut m = DMatrix::zeros(rank(k - 1) + e.nrows(), rank(k) + e.ncols());
+                m.slice_mut((0, 0), d.shape()).copy_from(&d);
+                m.slice_mut((d.nrows(), 0), f.shape()).copy_from(&f);
+                m.slice_mut((d.nrows(), d.ncols()), e.shape()).copy_from(&e);
+                m
+            })
+            .collect();
+        ChainComplex::new(differentials).unwrap()
+    }
+
+    /// The inclusion D -> cone(f), sending d to (0, d).
+    fn cone_inclusion(&self, cone: &ChainComplex) -> ChainMap {
+        let maps = (0..=cone.dim())
+            .map(|k| {
+                let mut m = DMatrix::zeros(cone.rank(k), self.target.rank(k));
+                let r = cone.rank(k) - self.target.rank(k);
+                m.slice_mut((r, 0), (self.target.rank(k), self.target.rank(k)))
+                    .fill_with_identity();
+                m
+            })
+            .collect();
+        ChainMap::new(self.target.clone(), cone.clone(), maps).unwrap()
+    }
+
+    /// The projection cone(f) -> ΣC, sending (c, d) to c.
+    fn cone_projection(&self, cone: &ChainComplex) -> ChainMap {
+        let shifted = self.source.shift();
+        let maps = (0..=cone.dim())
+            .map(|k| {
+                let mut m = DMatrix::zeros(shifted.rank(k), cone.rank(k));
+                m.slice_mut((0, 0), (shifted.rank(k), shifted.rank(k)))
+                    .fill_with_identity();
+                m
+            })
+            .collect();
+        ChainMap::new(cone.clone(), shifted, maps).unwrap()
+    }
+
+    /// The long exact sequence in homology of the mapping cone of f: C -> D,
+    ///
+    /// ```text
+    /// ... -> H_k(C) -> H_k(D) -> H_k(cone) -> H_{k-1}(C) -> ... -> H_0(D) -> H_0(cone) -> 0
+    /// ```
+    ///
+    /// starting from the highest k for which any of these can be non-zero. As for `induced_map`,
+    /// returns None for `Coefficients::Integers`.
+    pub fn long_exact_sequence(&self, coefficients: Coefficients) -> Option<LongExactSequence> {
+        let cone = self.mapping_cone();
+        let inclusion = self.cone_inclusion(&cone);
+        let projection = self.cone_projection(&cone);
+        let mut groups = vec![];
+        let mut ranks = vec![];
+        for k in (0..=cone.dim()).rev() {
+            let f = self.induced_map(k, coefficients)?;
+            let i = inclusion.induced_map(k, coefficients)?;
+            let p = projection.induced_map(k, coefficients)?;
+            groups.extend_from_slice(&[f.source, f.target, i.target]);
+            ranks.extend_from_slice(&[f.rank, i.rank, p.rank]);
+        }
+        // There is no map out of H_0(cone).
+        ranks.pop();
+        Some(LongExactSequence { groups, ranks })
+    }
+}
+
+/// A long exact sequence in homology, described by the ranks of the groups and the maps between
+/// them. The map ranks[i] goes from groups[i] to groups[i + 1].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LongExactSequence {
+    pub groups: Vec<usize>,
+    pub ranks: Vec<usize>,
+}
+
+impl LongExactSequence {
+    /// Whether the sequence is exact at every group, ie. the kernel of each map has the same rank
+    /// as the image of the one before. The sequence starts and ends with 0.
+    pub fn is_exact(&self) -> bool {
+        self.groups.iter()
+            .enumerate()
+            .all(|(i, &g)| {
+                let incoming = if i == 0 { 0 } else { self.ranks[i - 1] };
+                let outgoing = self.ranks.get(i).copied().unwrap_or(0);
+                incoming + outgoing == g
+            })
+    }
+}
+
+impl<S, T> From<&SimplicialMap<S, T>> for ChainMap where S: Ord + Clone, T: Ord + Clone {
+    /// The map of simplicial chain complexes, given by `SimplicialMap::chain_map`.
+    fn from(f: &SimplicialMap<S, T>) -> Self {
+        let dim = f.domain().dim().max(f.codomain().dim());
+        ChainMap {
+            source: ChainComplex::from(f.domain()),
+            target: ChainComplex::from(f.codomain()),
+            maps: (0..=dim).map(|k| f.chain_map::<i64>(k)).collect(),
+        }
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The long exact sequence of the pair (K, L), where K is self and L is a subcomplex,
+    ///
+    /// ```text
+    /// ... -> H_k(L) -> H_k(K) -> H_k(K, L) -> H_{k-1}(L) -> ... -> H_0(K, L) -> 0
+    /// ```
+    ///
+    /// computed as the long exact sequence of the mapping cone of the inclusion. Returns None for
+    /// `Coefficients::Integers`.
+    pub fn long_exact_sequence(&self, sub: &SimplicialComplex<T>, coefficients: Coefficients) -> Option<LongExactSequence> {
+        let inclusion = match SimplicialMap::from_fn(sub.clone(), self.clone(), |v| v.clone()) {
+            Ok(f) => f,
+            Err(_) => panic!("Cannot take the long exact sequence of a pair which is not a subcomplex"),
+        };
+        ChainMap::from(&inclusion).long_exact_sequence(coefficients)
+    }
+}
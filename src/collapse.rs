@@ -0,0 +1,130 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::facet_complex::FacetComplex;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The free faces of the complex, each paired with the unique simplex containing it. A simplex
+    /// is free if it is a proper face of exactly one simplex, which is then maximal and of one
+    /// dimension higher.
+    pub fn free_faces(&self) -> Vec<(Simplex<T>, Simplex<T>)> {
+        self.cofaces().into_iter()
+            .filter(|(_, c)| c.len() == 1)
+            .map(|(s, c)| (s, c.into_iter().next().unwrap()))
+            .collect()
+    }
+
+    /// Remove a free face along with the simplex containing it, which is an elementary collapse.
+    /// Returns None if the simplex is not a free face.
+    pub fn elementary_collapse(&self, s: &Simplex<T>) -> Option<SimplicialComplex<T>> {
+        let mut cofaces = self.simplices.iter().filter(|t| s.is_face(t) && *t != s);
+        let t = cofaces.next()?;
+        if cofaces.next().is_some() {
+            return None;
+        }
+        let mut simplices = self.simplices.clone();
+        simplices.remove(s);
+        simplices.remove(t);
+        Some(SimplicialComplex::new(simplices))
+    }
+
+    /// Repeatedly make elementary collapses until there are no free faces left. The result is a
+    /// subcomplex which is a deformation retract of the complex, so it has the same homology. The
+    /// result depends on the order of the collapses, and a contractible complex need not collapse
+    /// to a point, eg. the dunce hat has no free faces at all.
+    pub fn collapse(&self) -> SimplicialComplex<T> {
+        let mut cofaces = self.cofaces();
+        let mut simplices = self.simplices.clone();
+        let mut stack: Vec<Simplex<T>> = cofaces.iter()
+            .filter(|(_, c)| c.len() == 1)
+            .map(|(s, _)| s.clone())
+            .collect();
+        while let Some(s) = stack.pop() {
+            let t = match cofaces.get(&s) {
+                Some(c) if c.len() == 1 => c.iter().next().unwrap().clone(),
+                _ => continue,
+            };
+            // Removing s and t leaves the other faces of t and the faces of s with one fewer
+            // coface each, which may make them free.
+            for f in t.faces().chain(s.faces()) {
+                if f == s {
+                    continue;
+                }
+                let c = cofaces.get_mut(&f).unwrap();
+                c.remove(&t);
+                c.remove(&s);
+                if c.len() == 1 {
+                    stack.push(f);
+                }
+            }
+            cofaces.remove(&s);
+            cofaces.remove(&t);
+            simplices.remove(&s);
+            simplices.remove(&t);
+        }
+        SimplicialComplex::new(simplices)
+    }
+
+    /// Whether the complex collapses to a single vertex by elementary collapses. As collapsing
+    /// greedily can get stuck, this may be false for some collapsible complexes of dimension
+    /// greater than 2.
+    pub fn is_collapsible(&self) -> bool {
+        self.collapse().simplices.len() == 1
+    }
+
+    /// The vertices v which are dominated by another vertex w, ie. every maximal simplex
+    /// containing v also contains w, so the link of v is a cone with apex w. Each is paired with
+    /// the smallest vertex dominating it.
+    pub fn dominated_vertices(&self) -> Vec<(T, T)> {
+        dominated(&FacetComplex::from(self)).collect()
+    }
+
+    /// Repeatedly remove dominated vertices, along with every simplex containing them, until there
+    /// are none left. Each such removal is a strong collapse, so the result has the same homotopy
+    /// type, and it only depends on the complex up to isomorphism. This works on the maximal
+    /// simplices only, so it is well suited to flag complexes such as Vietoris-Rips complexes,
+    /// and can be followed by `collapse`.
+    pub fn strong_collapse(&self) -> SimplicialComplex<T> {
+        let mut k = FacetComplex::from(self);
+        loop {
+            let (v, _) = match dominated(&k).next() {
+                Some(x) => x,
+                None => return k.to_complex(),
+            };
+            k = FacetComplex::new(k.facets().iter()
+                .map(|s| s.vertices.iter()
+                    .filter(|&w| *w != v)
+                    .cloned()
+                    .collect::<Simplex<T>>()));
+        }
+    }
+
+    /// The simplices containing each simplex as a codimension 1 face.
+    fn cofaces(&self) -> BTreeMap<Simplex<T>, BTreeSet<Simplex<T>>> {
+        let mut cofaces: BTreeMap<Simplex<T>, BTreeSet<Simplex<T>>> = self.simplices.iter()
+            .map(|s| (s.clone(), BTreeSet::new()))
+            .collect();
+        for s in &self.simplices {
+            for f in s.faces() {
+                cofaces.entry(f).or_default().insert(s.clone());
+            }
+        }
+        cofaces
+    }
+}
+
+/// The dominated vertices of a complex given by its facets, with their smallest dominating
+/// vertices.
+fn dominated<T: Ord + Clone>(k: &FacetComplex<T>) -> impl Iterator<Item = (T, T)> + '_ {
+    k.vertices().into_iter()
+        .filter_map(move |v| {
+            let mut containing = k.facets().iter().filter(|s| s.vertices.contains(&v));
+            let mut common = containing.next()?.vertices.clone();
+            for s in containing {
+                common = common.intersection(&s.vertices).cloned().collect();
+            }
+            let w = common.into_iter().find(|w| *w != v)?;
+            Some((v, w))
+        })
+}
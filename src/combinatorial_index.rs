@@ -0,0 +1,173 @@
+use num_traits::{Num, PrimInt};
+
+use super::simplex::Simplex;
+
+/// Indexing of the simplices on the vertices `0..n` using the combinatorial number system, as in
+/// Ripser. The k-simplex with vertices v_0 < v_1 < ... < v_k has index
+///
+/// ```text
+/// C(v_0, 1) + C(v_1, 2) + ... + C(v_k, k + 1)
+/// ```
+///
+/// so the k-simplices are numbered `0..C(n, k + 1)` in colexicographic order. Faces and cofaces
+/// can then be computed from the index alone, without storing the complex. The index type `I` is
+/// usually `u64` or `u128`.
+#[derive(Clone, Debug)]
+pub struct CombinatorialIndex<I> {
+    n: usize,
+    /// binomials[k][m] = C(m, k) for m <= n and k <= max_dim + 1
+    binomials: Vec<Vec<I>>,
+}
+
+impl<I> CombinatorialIndex<I> where I: PrimInt {
+    /// Index simplices of dimension at most `max_dim` on `n` vertices. Cofaces are only available
+    /// for simplices of dimension less than `max_dim`.
+    ///
+    /// Returns None if the number of simplices does not fit in `I`.
+    pub fn new(n: usize, max_dim: usize) -> Option<Self> {
+        // Simplices of dimension k only use C(m, i) for i <= k + 1.
+        let mut binomials = vec![vec![I::zero(); n + 1]; max_dim + 2];
+        for m in 0..=n {
+            binomials[0][m] = I::one();
+            for k in 1..binomials.len() {
+                if m > 0 {
+                    binomials[k][m] = binomials[k - 1][m - 1].checked_add(&binomials[k][m - 1])?;
+                }
+            }
+        }
+        Some(CombinatorialIndex { n, binomials })
+    }
+
+    /// The number of vertices.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The largest dimension of the simplices which can be indexed.
+    pub fn max_dim(&self) -> usize {
+        self.binomials.len() - 2
+    }
+
+    /// The binomial coefficient C(m, k).
+    ///
+    /// # Panics
+    ///
+    /// Panics unless m <= n and k <= max_dim + 1.
+    pub fn binomial(&self, m: usize, k: usize) -> I {
+        assert!(m <= self.n, "C({}, {}) is out of range for {} vertices", m, k, self.n);
+        assert!(k < self.binomials.len(), "C({}, {}) is out of range for dimension {}", m, k, self.max_dim());
+        self.binomials[k][m]
+    }
+
+    /// The number of k-simplices on n vertices, ie. C(n, k + 1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if k > max_dim.
+    pub fn count(&self, k: usize) -> I {
+        self.binomial(self.n, k + 1)
+    }
+
+    fn index_of(&self, vertices: &[usize]) -> I {
+        vertices.iter()
+            .enumerate()
+            .fold(I::zero(), |acc, (i, &v)| acc + self.binomial(v, i + 1))
+    }
+
+    /// The index of a simplex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the simplex has a vertex which is not less than n, or its dimension is larger
+    /// than max_dim.
+    pub fn index(&self, s: &Simplex<usize>) -> I {
+        assert!(s.dim() <= self.max_dim(), "Dimension {} is larger than the maximum dimension", s.dim());
+        assert!(s.vertices.iter().all(|&v| v < self.n), "Simplex has a vertex out of range for {} vertices", self.n);
+        let vertices: Vec<usize> = s.vertices.iter().copied().collect();
+        self.index_of(&vertices)
+    }
+
+    /// The vertices of the k-simplex with the given index, in increasing order.
+    fn vertices(&self, index: I, k: usize) -> Vec<usize> {
+        assert!(k <= self.max_dim(), "Dimension {} is larger than the maximum dimension", k);
+        assert!(index < self.count(k), "Index out of range for {}-simplices", k);
+        let mut vertices = vec![0; k + 1];
+        let mut index = index;
+        let mut v = self.n;
+        // Greedily take the largest vertex v with C(v, i + 1) <= index, starting from the top.
+        for i in (0..=k).rev() {
+            v -= 1;
+            while self.binomial(v, i + 1) > index {
+                v -= 1;
+            }
+            index = index - self.binomial(v, i + 1);
+            vertices[i] = v;
+        }
+        vertices
+    }
+
+    /// The k-simplex with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if k > max_dim or the index is not less than `count(k)`.
+    pub fn simplex(&self, index: I, k: usize) -> Simplex<usize> {
+        self.vertices(index, k).into_iter().collect()
+    }
+
+    /// The column of the k-th boundary map for the k-simplex with the given index, as a list of
+    /// (index of face, coefficient) pairs. The coefficients agree with `Simplex::boundary_coeff`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if k > max_dim or the index is not less than `count(k)`.
+    pub fn boundary<U: Num>(&self, index: I, k: usize) -> Vec<(I, U)> {
+        if k == 0 {
+            return vec![];
+        }
+        let vertices = self.vertices(index, k);
+        (0..=k).map(|j| {
+            // Removing v_j shifts every later vertex down by one position.
+            let face = vertices.iter()
+                .enumerate()
+                .filter(|&(i, _)| i != j)
+                .fold(I::zero(), |acc, (i, &v)| {
+                    acc + self.binomial(v, if i < j { i + 1 } else { i })
+                });
+            (face, sign(j))
+        }).collect()
+    }
+
+    /// The row of the (k+1)-th boundary map for the k-simplex with the given index, as a list of
+    /// (index of coface, coefficient) pairs, ie. the column of the k-th coboundary map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if k >= max_dim, as the cofaces would be too large to index, or the index is not
+    /// less than `count(k)`.
+    pub fn coboundary<U: Num>(&self, index: I, k: usize) -> Vec<(I, U)> {
+        assert!(k < self.max_dim(), "Cofaces of {}-simplices are larger than the maximum dimension", k);
+        let vertices = self.vertices(index, k);
+        (0..self.n)
+            .filter(|v| !vertices.contains(v))
+            .map(|v| {
+                // The position at which v is inserted.
+                let p = vertices.iter().filter(|&&w| w < v).count();
+                let coface = vertices.iter()
+                    .enumerate()
+                    .fold(self.binomial(v, p + 1), |acc, (i, &w)| {
+                        acc + self.binomial(w, if i < p { i + 1 } else { i + 2 })
+                    });
+                (coface, sign(p))
+            })
+            .collect()
+    }
+}
+
+fn sign<U: Num>(i: usize) -> U {
+    if i.is_multiple_of(2) {
+        U::one()
+    } else {
+        U::zero() - U::one()
+    }
+}
@@ -0,0 +1,76 @@
+use super::combinatorial_index::CombinatorialIndex;
+use super::simplicial_complex::SimplicialComplex;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The f-vector (f_0, ..., f_dim), where f_k is the number of k-simplices. It is empty for
+    /// the empty complex.
+    pub fn f_vector(&self) -> Vec<usize> {
+        let mut f = vec![];
+        for s in &self.simplices {
+            if f.len() <= s.dim() {
+                f.resize(s.dim() + 1, 0);
+            }
+            f[s.dim()] += 1;
+        }
+        f
+    }
+
+    /// The h-vector (h_0, ..., h_d), where d = dim + 1, given by
+    ///
+    /// ```text
+    /// h_k = Σ_{i=0}^{k} (-1)^{k-i} C(d-i, k-i) f_{i-1}
+    /// ```
+    ///
+    /// with f_{-1} = 1 counting the empty face. Equivalently, Σ h_k x^{d-k} = Σ f_{i-1} (x-1)^{d-i}.
+    /// The h-vector of the boundary of a simplex is all ones.
+    pub fn h_vector(&self) -> Vec<i64> {
+        let mut f = vec![1];
+        f.extend(self.f_vector().into_iter().map(|x| x as i64));
+        let d = f.len() - 1;
+        let c = binomials(d);
+        (0..=d)
+            .map(|k| (0..=k)
+                .map(|i| {
+                    let t = c.binomial(d - i, k - i) * f[i];
+                    if (k - i).is_multiple_of(2) { t } else { -t }
+                })
+                .sum())
+            .collect()
+    }
+
+    /// The g-vector (g_0, ..., g_{d/2}), where g_0 = 1 and g_k = h_k - h_{k-1}. By the g-theorem,
+    /// the g-vector of the boundary of a simplicial polytope is non-negative.
+    pub fn g_vector(&self) -> Vec<i64> {
+        let h = self.h_vector();
+        let d = h.len() - 1;
+        (0..=d / 2)
+            .map(|k| if k == 0 { h[0] } else { h[k] - h[k - 1] })
+            .collect()
+    }
+
+    /// Whether the h-vector satisfies the Dehn–Sommerville relations in the form due to Klee,
+    ///
+    /// ```text
+    /// h_{d-k} - h_k = (-1)^k C(d, k) (χ - χ(S^{d-1}))
+    /// ```
+    ///
+    /// for every k. These hold for every homology sphere, where they say that h_k = h_{d-k}, and
+    /// more generally for every homology manifold without boundary. A complex which fails them is
+    /// neither.
+    pub fn satisfies_dehn_sommerville(&self) -> bool {
+        let h = self.h_vector();
+        let d = h.len() - 1;
+        let c = binomials(d);
+        let sphere = if d.is_multiple_of(2) { 0 } else { 2 };
+        let defect = self.euler() as i64 - sphere;
+        (0..=d).all(|k| {
+            let t = c.binomial(d, k) * defect;
+            h[d - k] - h[k] == if k.is_multiple_of(2) { t } else { -t }
+        })
+    }
+}
+
+/// The binomial coefficients C(m, k) for m, k <= d.
+fn binomials(d: usize) -> CombinatorialIndex<i64> {
+    CombinatorialIndex::new(d, d).expect("Binomial coefficient does not fit in an i64")
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+/// A simplicial complex stored by its facets, ie. its maximal simplices. The complex is the set of
+/// all faces of the facets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FacetComplex<T> {
+    facets: BTreeSet<Simplex<T>>,
+}
+
+impl<T> FacetComplex<T> where T: Ord + Clone {
+    /// The complex generated by the given simplices. Simplices which are faces of other simplices
+    /// are discarded, so only the maximal ones are kept.
+    pub fn new<I: IntoIterator<Item = Simplex<T>>>(simplices: I) -> Self {
+        let mut simplices: Vec<_> = simplices.into_iter().collect();
+        // Any simplex containing s has larger dimension, so it is enough to check s against the
+        // facets we have already found.
+        simplices.sort_by_key(|s| std::cmp::Reverse(s.dim()));
+        let mut facets: BTreeSet<Simplex<T>> = BTreeSet::new();
+        for s in simplices {
+            if !facets.iter().any(|f| s.is_face(f)) {
+                facets.insert(s);
+            }
+        }
+        FacetComplex { facets }
+    }
+
+    /// The maximal simplices of the complex.
+    pub fn facets(&self) -> &BTreeSet<Simplex<T>> {
+        &self.facets
+    }
+
+    pub fn vertices(&self) -> BTreeSet<T> {
+        self.facets.iter()
+            .flat_map(|s| &s.vertices)
+            .cloned()
+            .collect()
+    }
+
+    /// The dimension of the complex is the largest dimension of any facet.
+    pub fn dim(&self) -> usize {
+        self.facets.iter()
+            .map(|s| s.dim())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether s is a simplex of the complex, ie. a face of some facet.
+    pub fn is_face_of_complex(&self, s: &Simplex<T>) -> bool {
+        self.facets.iter().any(|f| s.is_face(f))
+    }
+
+    /// Returns the set of k-simplices in the complex.
+    pub fn dim_simplices(&self, k: usize) -> BTreeSet<Simplex<T>> {
+        self.facets.iter()
+            .filter(|f| f.dim() >= k)
+            .flat_map(|f| subsets(&f.vertices.iter().cloned().collect::<Vec<_>>(), k + 1))
+            .collect()
+    }
+
+    /// The f-vector (f_0, ..., f_d), where f_k is the number of k-simplices. It is empty for the
+    /// empty complex.
+    ///
+    /// The faces are counted without listing them, by inclusion–exclusion: the faces of a facet
+    /// which are not faces of an earlier facet are all of its faces, minus the faces of the
+    /// complex generated by its intersections with the earlier facets. That complex has smaller
+    /// dimension, so it is counted recursively. This can still take exponential time in the
+    /// number of facets, but uses little memory.
+    pub fn f_vector(&self) -> Vec<usize> {
+        if self.facets.is_empty() {
+            return vec![];
+        }
+        let mut f = vec![0; self.dim() + 1];
+        let facets: Vec<&Simplex<T>> = self.facets.iter().collect();
+        for (i, s) in facets.iter().enumerate() {
+            // A facet with n vertices has C(n, k + 1) faces of dimension k.
+            let n = s.vertices.len();
+            let mut c = 1;
+            for (k, x) in f.iter_mut().enumerate().take(n) {
+                c = c * (n - k) / (k + 1);
+                *x += c;
+            }
+            let overlaps = FacetComplex::new(facets[..i].iter()
+                .map(|t| s.vertices.intersection(&t.vertices).cloned().collect::<Simplex<T>>())
+                .filter(|t| !t.vertices.is_empty()));
+            for (k, x) in overlaps.f_vector().into_iter().enumerate() {
+                f[k] -= x;
+            }
+        }
+        f
+    }
+
+    /// The simplicial complex of all faces of the facets.
+    pub fn to_complex(&self) -> SimplicialComplex<T> {
+        SimplicialComplex::closure(self.facets.iter().cloned())
+    }
+}
+
+impl<T> From<&SimplicialComplex<T>> for FacetComplex<T> where T: Ord + Clone {
+    /// The facets of a simplicial complex, which is assumed to be closed under taking faces.
+    fn from(k: &SimplicialComplex<T>) -> Self {
+        // In a simplicial complex, a simplex is maximal iff it is not a codimension 1 face of
+        // another simplex.
+        let faces: BTreeSet<_> = k.simplices.iter()
+            .flat_map(|s| s.faces())
+            .collect();
+        let facets = k.simplices.iter()
+            .filter(|s| !faces.contains(s))
+            .cloned()
+            .collect();
+        FacetComplex { facets }
+    }
+}
+
+impl<T> From<&FacetComplex<T>> for SimplicialComplex<T> where T: Ord + Clone {
+    fn from(k: &FacetComplex<T>) -> Self {
+        k.to_complex()
+    }
+}
+
+/// All subsets of the given size of a sorted list of vertices.
+fn subsets<T: Ord + Clone>(vertices: &[T], size: usize) -> Vec<Simplex<T>> {
+    fn go<T: Ord + Clone>(vertices: &[T], size: usize, current: &mut Vec<T>, out: &mut Vec<Simplex<T>>) {
+        if current.len() == size {
+            out.push(current.iter().cloned().collect());
+            return;
+        }
+        for (i, v) in vertices.iter().enumerate() {
+            if vertices.len() - i < size - current.len() {
+                break;
+            }
+            current.push(v.clone());
+            go(&vertices[i + 1..], size, current, out);
+            current.pop();
+        }
+    }
+    let mut out = vec![];
+    go(vertices, size, &mut vec![], &mut out);
+    out
+}
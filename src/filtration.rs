@@ -1,11 +1,69 @@
 use super::simplicial_complex::SimplicialComplex;
+use super::simplex_tree::SimplexTree;
 
+/// An increasing sequence of simplicial complexes K_0 ⊆ K_1 ⊆ ... ⊆ K_n.
+///
+/// The complexes are stored together in a single `SimplexTree`, where each simplex is labelled by
+/// the filtration value at which it first appears, and K_i is the sublevel complex at the i-th
+/// value.
 pub struct Filtration<T> {
-    data: Vec<SimplicialComplex<T>>
+    tree: SimplexTree<T>,
+    values: Vec<f64>,
 }
 
-impl<T> Filtration<T> {
+impl<T> Filtration<T> where T: Ord + Clone {
+    /// The filtration given by a list of complexes, each of which must contain the one before.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the complexes are not nested, as each simplex is only stored
+    /// with the first complex containing it.
     pub fn new(data: Vec<SimplicialComplex<T>>) -> Self {
-        Filtration { data }
+        debug_assert!(data.windows(2).all(|w| w[0].is_subcomplex(&w[1])),
+            "Each complex of a filtration must contain the one before");
+        let mut tree = SimplexTree::new();
+        for (i, k) in data.iter().enumerate() {
+            // Simplices already in the tree appeared in an earlier complex.
+            for s in &k.simplices {
+                if !tree.contains(s) {
+                    tree.insert_with_filtration(s, i as f64);
+                }
+            }
+        }
+        Filtration {
+            tree,
+            values: (0..data.len()).map(|i| i as f64).collect(),
+        }
+    }
+
+    /// The filtration of the sublevel complexes of a simplex tree at each of the given values,
+    /// which must be increasing.
+    pub fn from_tree(tree: SimplexTree<T>, values: Vec<f64>) -> Self {
+        Filtration { tree, values }
+    }
+
+    /// The simplex tree of the last complex, labelled by filtration values.
+    pub fn tree(&self) -> &SimplexTree<T> {
+        &self.tree
+    }
+
+    /// The i-th complex in the filtration.
+    pub fn complex(&self, i: usize) -> SimplicialComplex<T> {
+        self.tree.sublevel_complex(self.values[i])
+    }
+}
+
+impl<T> Filtration<T> {
+    /// The filtration values of the complexes.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
 }
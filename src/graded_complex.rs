@@ -0,0 +1,125 @@
+use std::fmt::Debug;
+
+use nalgebra::DMatrix;
+use num_traits::Num;
+
+use super::boundary::rank;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+/// A simplicial complex with its simplices split up by dimension, where the k-simplices are kept
+/// in a sorted list, so the position of a simplex is found by binary search. The positions agree
+/// with the rows and columns of `SimplicialComplex::boundary`.
+///
+/// Unlike `SimplicialComplex`, counting or listing the simplices of a given dimension does not
+/// scan the whole complex, and boundary matrices are built from the faces of each simplex rather
+/// than by comparing every pair of simplices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GradedComplex<T> {
+    simplices: Vec<Vec<Simplex<T>>>,
+}
+
+impl<T> GradedComplex<T> where T: Ord + Clone {
+    /// The largest dimension of any simplex, or 0 if the complex is empty.
+    pub fn dim(&self) -> usize {
+        self.simplices.len().saturating_sub(1)
+    }
+
+    /// The number of simplices.
+    pub fn len(&self) -> usize {
+        self.simplices.iter().map(|s| s.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.simplices.is_empty()
+    }
+
+    /// The number of k-simplices.
+    pub fn count(&self, k: usize) -> usize {
+        self.simplices.get(k).map_or(0, |s| s.len())
+    }
+
+    /// The f-vector (f_0, ..., f_dim), where f_k is the number of k-simplices.
+    pub fn f_vector(&self) -> Vec<usize> {
+        self.simplices.iter().map(|s| s.len()).collect()
+    }
+
+    /// The k-simplices, in order.
+    pub fn dim_simplices(&self, k: usize) -> &[Simplex<T>] {
+        self.simplices.get(k).map_or(&[], |s| s)
+    }
+
+    /// The position of a simplex among the simplices of its dimension, if it is in the complex.
+    pub fn index(&self, s: &Simplex<T>) -> Option<usize> {
+        self.simplices.get(s.dim())?.binary_search(s).ok()
+    }
+
+    pub fn contains(&self, s: &Simplex<T>) -> bool {
+        self.index(s).is_some()
+    }
+
+    /// The Euler characteristic, ie. the alternating sum of the f-vector.
+    pub fn euler(&self) -> isize {
+        self.simplices.iter()
+            .enumerate()
+            .map(|(k, s)| if k % 2 == 0 { s.len() as isize } else { -(s.len() as isize) })
+            .sum()
+    }
+
+    /// The k-th boundary map, which is the same matrix as `SimplicialComplex::boundary`.
+    pub fn boundary<U: 'static + Num + Clone + Debug>(&self, k: usize) -> DMatrix<U> {
+        if k == 0 {
+            return DMatrix::zeros(0, self.count(0));
+        }
+        let mut m = DMatrix::zeros(self.count(k - 1), self.count(k));
+        for (j, s) in self.dim_simplices(k).iter().enumerate() {
+            for t in s.faces() {
+                if let Some(i) = self.index(&t) {
+                    m[(i, j)] = s.boundary_coeff(&t);
+                }
+            }
+        }
+        m
+    }
+
+    /// All of the Betti numbers b_0, ..., b_dim, which is empty for the empty complex, as for
+    /// `f_vector`. Each boundary matrix is only built and reduced once, as its rank is shared
+    /// between two Betti numbers.
+    pub fn betti_numbers(&self) -> Vec<usize> {
+        if self.is_empty() {
+            return vec![];
+        }
+        let ranks: Vec<usize> = (0..=self.dim() + 1)
+            .map(|k| rank(&self.boundary::<f64>(k)))
+            .collect();
+        (0..=self.dim())
+            .map(|k| self.count(k) - ranks[k] - ranks[k + 1])
+            .collect()
+    }
+
+    pub fn to_complex(&self) -> SimplicialComplex<T> {
+        SimplicialComplex::new(self.simplices.iter().flatten().cloned().collect())
+    }
+}
+
+impl<T> From<&SimplicialComplex<T>> for GradedComplex<T> where T: Ord + Clone {
+    fn from(k: &SimplicialComplex<T>) -> Self {
+        let mut simplices: Vec<Vec<Simplex<T>>> = vec![];
+        // The simplices come out in order, so each bucket ends up sorted.
+        for s in &k.simplices {
+            if simplices.len() <= s.dim() {
+                simplices.resize(s.dim() + 1, vec![]);
+            }
+            simplices[s.dim()].push(s.clone());
+        }
+        GradedComplex { simplices }
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// All of the Betti numbers b_0, ..., b_dim, computed together. This is cheaper than calling
+    /// `betti` for each k, which builds two boundary matrices each time.
+    pub fn betti_numbers(&self) -> Vec<usize> {
+        GradedComplex::from(self).betti_numbers()
+    }
+}
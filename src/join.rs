@@ -0,0 +1,53 @@
+use std::collections::BTreeSet;
+
+use super::simplicial_complex::SimplicialComplex;
+
+/// A vertex of a complex built out of two complexes, tagged by the complex it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JoinVertex<A, B> {
+    Left(A),
+    Right(B),
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The join of two complexes has simplices s, t and s ∪ t for every s in self and t in other,
+    /// where the vertices of the two complexes are taken to be disjoint.
+    pub fn join<U: Ord + Clone>(&self, other: &SimplicialComplex<U>) -> SimplicialComplex<JoinVertex<T, U>> {
+        let left = self.map_vertices(JoinVertex::Left);
+        let right = other.map_vertices(JoinVertex::Right);
+        let mut simplices: BTreeSet<_> = left.simplices.iter()
+            .flat_map(|s| right.simplices.iter()
+                .map(move |t| s.vertices.union(&t.vertices).cloned().collect()))
+            .collect();
+        simplices.extend(left.simplices);
+        simplices.extend(right.simplices);
+        SimplicialComplex::new(simplices)
+    }
+
+    /// The cone over a complex is its join with a point. The apex is `JoinVertex::Right(0)`.
+    pub fn cone(&self) -> SimplicialComplex<JoinVertex<T, usize>> {
+        self.join(&SimplicialComplex::solid(0))
+    }
+
+    /// The suspension of a complex is its join with two points, `JoinVertex::Right(0)` and
+    /// `JoinVertex::Right(1)`. The reduced homology of the suspension is that of the complex
+    /// shifted up by one dimension.
+    pub fn suspension(&self) -> SimplicialComplex<JoinVertex<T, usize>> {
+        self.join(&SimplicialComplex::hollow(1))
+    }
+
+    /// The wedge sum of two complexes, given by taking their disjoint union and identifying the
+    /// vertex v of self with the vertex w of other. The identified vertex is `JoinVertex::Left(v)`.
+    pub fn wedge<U: Ord + Clone>(&self, v: T, other: &SimplicialComplex<U>, w: U) -> SimplicialComplex<JoinVertex<T, U>> {
+        if !self.vertices.contains(&v) || !other.vertices.contains(&w) {
+            panic!("Cannot take the wedge sum at a vertex which is not in the complex");
+        }
+        let left = self.map_vertices(JoinVertex::Left);
+        let right = other.map_vertices(|x| if x == w {
+            JoinVertex::Left(v.clone())
+        } else {
+            JoinVertex::Right(x)
+        });
+        left.union(&right)
+    }
+}
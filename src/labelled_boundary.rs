@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use nalgebra::{DMatrix, DVector};
+use num_traits::Num;
+
+use super::chain::Chain;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+/// The k-th boundary matrix of a complex, along with the (k-1)-simplices labelling its rows and
+/// the k-simplices labelling its columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LabelledBoundary<T, U: 'static + Clone + PartialEq + Debug> {
+    matrix: DMatrix<U>,
+    rows: Vec<Simplex<T>>,
+    columns: Vec<Simplex<T>>,
+    row_index: BTreeMap<Simplex<T>, usize>,
+    column_index: BTreeMap<Simplex<T>, usize>,
+}
+
+impl<T, U> LabelledBoundary<T, U> where T: Ord + Clone, U: 'static + Num + Clone + Debug {
+    pub fn matrix(&self) -> &DMatrix<U> {
+        &self.matrix
+    }
+
+    /// The (k-1)-simplices, in the order of the rows.
+    pub fn rows(&self) -> &[Simplex<T>] {
+        &self.rows
+    }
+
+    /// The k-simplices, in the order of the columns.
+    pub fn columns(&self) -> &[Simplex<T>] {
+        &self.columns
+    }
+
+    /// The row of a (k-1)-simplex, if it is in the complex.
+    pub fn row_index(&self, s: &Simplex<T>) -> Option<usize> {
+        self.row_index.get(s).copied()
+    }
+
+    /// The column of a k-simplex, if it is in the complex.
+    pub fn column_index(&self, s: &Simplex<T>) -> Option<usize> {
+        self.column_index.get(s).copied()
+    }
+
+    /// The (k-1)-chain with the given coefficients on the rows.
+    pub fn row_chain(&self, v: &DVector<U>) -> Chain<T, U> {
+        self.rows.iter().cloned().zip(v.iter().cloned()).collect()
+    }
+
+    /// The k-chain with the given coefficients on the columns, eg. for a vector in the kernel.
+    pub fn column_chain(&self, v: &DVector<U>) -> Chain<T, U> {
+        self.columns.iter().cloned().zip(v.iter().cloned()).collect()
+    }
+
+    /// The coefficients of a k-chain on the columns. Panics if the chain has a simplex which is
+    /// not a column.
+    pub fn column_vector(&self, c: &Chain<T, U>) -> DVector<U> {
+        let mut v = DVector::zeros(self.columns.len());
+        for (s, x) in c.terms() {
+            let j = self.column_index(s).expect("The chain is not made of columns of the boundary matrix");
+            v[j] = x.clone();
+        }
+        v
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The k-th boundary matrix, as given by `boundary`, labelled by the simplices of its rows and
+    /// columns.
+    pub fn labelled_boundary<U: 'static + Num + Clone + Debug>(&self, k: usize) -> LabelledBoundary<T, U> {
+        let rows: Vec<Simplex<T>> = if k == 0 { vec![] } else { self.dim_simplices(k - 1).into_iter().collect() };
+        let columns: Vec<Simplex<T>> = self.dim_simplices(k).into_iter().collect();
+        let index = |v: &[Simplex<T>]| v.iter()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), i))
+            .collect();
+        LabelledBoundary {
+            matrix: self.boundary(k),
+            row_index: index(&rows),
+            column_index: index(&columns),
+            rows,
+            columns,
+        }
+    }
+}
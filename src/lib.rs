@@ -4,3 +4,25 @@ pub mod boundary;
 pub mod vietoris_rips;
 pub mod linear_algebra;
 pub mod filtration;
+pub mod simplex_tree;
+pub mod combinatorial_index;
+pub mod facet_complex;
+pub mod star;
+pub mod subcomplex;
+pub mod join;
+pub mod subdivision;
+pub mod product;
+pub mod triangulations;
+pub mod random;
+pub mod relative;
+pub mod simplicial_map;
+pub mod chain_complex;
+pub mod chain_map;
+pub mod tensor_product;
+pub mod chain;
+pub mod labelled_boundary;
+pub mod graded_complex;
+pub mod face_numbers;
+pub mod manifold;
+pub mod orientation;
+pub mod collapse;
@@ -0,0 +1,48 @@
+use nalgebra::DMatrix;
+
+use super::integer;
+
+/// The ring of coefficients to compute homology with. Chain complexes are always given by integer
+/// matrices, which are then reduced into the chosen ring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Coefficients {
+    /// The integers. Ranks are the ranks of the free parts, which agree with the rationals.
+    Integers,
+    /// The rationals.
+    Rationals,
+    /// The finite field Z/p, for a prime p.
+    Prime(Prime),
+}
+
+/// A prime number, which can only be constructed by checking that it is prime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Prime(i64);
+
+impl Prime {
+    /// The prime p, or None if p is not prime.
+    pub fn new(p: i64) -> Option<Prime> {
+        if integer::is_prime(p) { Some(Prime(p)) } else { None }
+    }
+
+    pub fn get(self) -> i64 {
+        self.0
+    }
+}
+
+impl Coefficients {
+    /// The finite field Z/p, or None if p is not prime.
+    pub fn prime(p: i64) -> Option<Coefficients> {
+        Prime::new(p).map(Coefficients::Prime)
+    }
+
+    /// The rank of an integer matrix over this ring.
+    pub fn rank(&self, m: &DMatrix<i64>) -> usize {
+        if m.nrows() * m.ncols() == 0 {
+            return 0;
+        }
+        match self {
+            Coefficients::Integers | Coefficients::Rationals => integer::rank(m),
+            Coefficients::Prime(p) => integer::rank_mod_p(m, p.get()),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use nalgebra::DMatrix;
+
+/// The invariant factors of an integer matrix, ie. the non-zero diagonal entries d_1 | d_2 | ...
+/// of its Smith normal form over the integers. All of them are positive, and the number of them
+/// is the rank of the matrix over the rationals.
+///
+/// Panics if an intermediate entry overflows.
+pub fn invariant_factors(m: &DMatrix<i64>) -> Vec<i64> {
+    let mut a = m.clone();
+    let (rows, cols) = a.shape();
+    let mut factors = vec![];
+    for t in 0..rows.min(cols) {
+        // Move the smallest non-zero entry of the remaining submatrix to (t, t)
+        let pivot = match smallest_entry(&a, t) {
+            Some(p) => p,
+            None => break,
+        };
+        a.swap_rows(t, pivot.0);
+        a.swap_columns(t, pivot.1);
+        loop {
+            // Clear out the t-th column and row. Whenever a remainder is left over it is smaller
+            // than the pivot, so it becomes the new pivot and we go again.
+            let mut done = true;
+            for i in (t + 1)..rows {
+                let q = a[(i, t)] / a[(t, t)];
+                if q != 0 {
+                    add_row(&mut a, i, t, -q, t);
+                }
+                if a[(i, t)] != 0 {
+                    a.swap_rows(t, i);
+                    done = false;
+                }
+            }
+            for j in (t + 1)..cols {
+                let q = a[(t, j)] / a[(t, t)];
+                if q != 0 {
+                    add_col(&mut a, j, t, -q, t);
+                }
+                if a[(t, j)] != 0 {
+                    a.swap_columns(t, j);
+                    done = false;
+                }
+            }
+            if !done {
+                continue;
+            }
+            // The pivot must divide everything left, otherwise we add the offending row to the
+            // t-th row and reduce again. A unit divides everything.
+            let d = a[(t, t)];
+            if d.abs() == 1 {
+                break;
+            }
+            match (t + 1..rows)
+                .find(|&i| (t + 1..cols).any(|j| a[(i, j)] % d != 0)) {
+                Some(i) => add_row(&mut a, t, i, 1, t),
+                None => break,
+            }
+        }
+        factors.push(a[(t, t)].abs());
+    }
+    factors
+}
+
+/// The rank of an integer matrix over the rationals.
+pub fn rank(m: &DMatrix<i64>) -> usize {
+    invariant_factors(m).len()
+}
+
+/// Whether n is prime, by the Miller-Rabin test, which is deterministic for these bases and any
+/// n < 2^64.
+pub fn is_prime(n: i64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    const BASES: [i64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if let Some(&b) = BASES.iter().find(|&&b| n % b == 0) {
+        return n == b;
+    }
+    // n - 1 = 2^s d with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&b| {
+        let mut x = pow_mod(b, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..s).any(|_| {
+            x = mul_mod(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+/// The rank of an integer matrix over the finite field Z/p. Panics if p is not prime.
+pub fn rank_mod_p(m: &DMatrix<i64>, p: i64) -> usize {
+    assert!(is_prime(p), "{} is not prime", p);
+    let mut a = m.map(|x| x.rem_euclid(p));
+    let (rows, cols) = a.shape();
+    let mut r = 0;
+    for j in 0..cols {
+        let i = match (r..rows).find(|&i| a[(i, j)] != 0) {
+            Some(i) => i,
+            None => continue,
+        };
+        a.swap_rows(r, i);
+        let inv = inverse_mod_p(a[(r, j)], p);
+        for i in 0..rows {
+            if i != r && a[(i, j)] != 0 {
+                let c = mul_mod(a[(i, j)], inv, p);
+                for l in j..cols {
+                    a[(i, l)] = (a[(i, l)] - mul_mod(c, a[(r, l)], p)).rem_euclid(p);
+                }
+            }
+        }
+        r += 1;
+    }
+    r
+}
+
+fn smallest_entry(a: &DMatrix<i64>, t: usize) -> Option<(usize, usize)> {
+    let (rows, cols) = a.shape();
+    let mut best: Option<(usize, usize)> = None;
+    // The matrix is stored by columns, so we go down each column in turn. A unit can't be
+    // beaten, so we stop as soon as we see one.
+    for j in t..cols {
+        for i in t..rows {
+            let x = a[(i, j)].abs();
+            if x != 0 && best.is_none_or(|b| x < a[b].abs()) {
+                best = Some((i, j));
+                if x == 1 {
+                    return best;
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Replace row i with row i + lambda * row j, where both rows are zero before column `from`.
+fn add_row(a: &mut DMatrix<i64>, i: usize, j: usize, lambda: i64, from: usize) {
+    for k in from..a.ncols() {
+        if a[(j, k)] != 0 {
+            a[(i, k)] = lambda.checked_mul(a[(j, k)])
+                .and_then(|x| x.checked_add(a[(i, k)]))
+                .expect("Integer overflow in Smith normal form");
+        }
+    }
+}
+
+/// Replace column i with column i + lambda * column j, where both columns are zero before row
+/// `from`.
+fn add_col(a: &mut DMatrix<i64>, i: usize, j: usize, lambda: i64, from: usize) {
+    for k in from..a.nrows() {
+        if a[(k, j)] != 0 {
+            a[(k, i)] = lambda.checked_mul(a[(k, j)])
+                .and_then(|x| x.checked_add(a[(k, i)]))
+                .expect("Integer overflow in Smith normal form");
+        }
+    }
+}
+
+/// The inverse of a non-zero x modulo a prime p, by Fermat's little theorem.
+fn inverse_mod_p(x: i64, p: i64) -> i64 {
+    pow_mod(x, p - 2, p)
+}
+
+fn pow_mod(x: i64, mut exp: i64, p: i64) -> i64 {
+    let (mut base, mut result) = (x % p, 1);
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, p);
+        }
+        base = mul_mod(base, base, p);
+        exp /= 2;
+    }
+    result
+}
+
+/// x y mod p, without overflowing for large p.
+fn mul_mod(x: i64, y: i64, p: i64) -> i64 {
+    (x as i128 * y as i128).rem_euclid(p as i128) as i64
+}
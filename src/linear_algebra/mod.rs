@@ -1,2 +1,5 @@
 pub mod operations;
 pub mod smith;
+pub mod integer;
+pub mod coefficients;
+pub mod sparse;
@@ -63,7 +63,7 @@ impl<T> Operation<T> where T: ComplexField + Copy {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Operations<T> {
     ops: Vec<Operation<T>>,
 }
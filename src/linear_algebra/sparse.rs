@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::ops::Neg;
+
+use nalgebra::DMatrix;
+
+/// A sparse integer matrix, stored as a list of columns. Each column is a list of (row,
+/// coefficient) pairs, sorted by row and with no zero coefficients, so that equal matrices have
+/// equal representations. Boundary matrices have only k + 1 entries in each column, so this is
+/// much smaller than a dense matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMatrix {
+    nrows: usize,
+    columns: Vec<Vec<(usize, i64)>>,
+}
+
+impl SparseMatrix {
+    /// The matrix with the given number of rows and the given columns, in any order. Entries in
+    /// the same position are added together. Panics if a row is out of range.
+    pub fn new(nrows: usize, columns: Vec<Vec<(usize, i64)>>) -> Self {
+        let columns = columns.into_iter()
+            .map(|c| {
+                let mut entries: BTreeMap<usize, i64> = BTreeMap::new();
+                for (i, x) in c {
+                    assert!(i < nrows, "Row {} is out of range for a matrix with {} rows", i, nrows);
+                    *entries.entry(i).or_insert(0) += x;
+                }
+                entries.into_iter().filter(|&(_, x)| x != 0).collect()
+            })
+            .collect();
+        SparseMatrix { nrows, columns }
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// The non-zero entries of the j-th column, sorted by row.
+    pub fn column(&self, j: usize) -> &[(usize, i64)] {
+        &self.columns[j]
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.columns.iter().all(|c| c.is_empty())
+    }
+
+    pub fn to_dense(&self) -> DMatrix<i64> {
+        let mut m = DMatrix::zeros(self.nrows, self.ncols());
+        for (j, c) in self.columns.iter().enumerate() {
+            for &(i, x) in c {
+                m[(i, j)] = x;
+            }
+        }
+        m
+    }
+
+    /// The product self * other, or None if an entry overflows. Panics if the shapes do not
+    /// match.
+    pub fn checked_mul(&self, other: &SparseMatrix) -> Option<SparseMatrix> {
+        assert_eq!(self.ncols(), other.nrows, "Cannot multiply matrices of incompatible shapes");
+        let columns = other.columns.iter()
+            .map(|c| {
+                let mut entries: BTreeMap<usize, i64> = BTreeMap::new();
+                for &(k, y) in c {
+                    for &(i, x) in &self.columns[k] {
+                        let e = entries.entry(i).or_insert(0);
+                        *e = e.checked_add(x.checked_mul(y)?)?;
+                    }
+                }
+                Some(entries.into_iter().filter(|&(_, x)| x != 0).collect())
+            })
+            .collect::<Option<_>>()?;
+        Some(SparseMatrix { nrows: self.nrows, columns })
+    }
+}
+
+impl From<&DMatrix<i64>> for SparseMatrix {
+    fn from(m: &DMatrix<i64>) -> Self {
+        SparseMatrix {
+            nrows: m.nrows(),
+            columns: m.column_iter()
+                .map(|c| c.iter()
+                    .enumerate()
+                    .filter(|&(_, &x)| x != 0)
+                    .map(|(i, &x)| (i, x))
+                    .collect())
+                .collect(),
+        }
+    }
+}
+
+impl From<DMatrix<i64>> for SparseMatrix {
+    fn from(m: DMatrix<i64>) -> Self {
+        SparseMatrix::from(&m)
+    }
+}
+
+impl Neg for &SparseMatrix {
+    type Output = SparseMatrix;
+
+    fn neg(self) -> SparseMatrix {
+        SparseMatrix {
+            nrows: self.nrows,
+            columns: self.columns.iter()
+                .map(|c| c.iter().map(|&(i, x)| (i, -x)).collect())
+                .collect(),
+        }
+    }
+}
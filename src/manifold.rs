@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+
+use super::facet_complex::FacetComplex;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+/// The reasons a complex can fail to be a pseudomanifold or a combinatorial manifold, along with
+/// all of the simplices responsible, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManifoldError<T> {
+    /// The maximal simplices whose dimension is less than the dimension of the complex.
+    NotPure(Vec<Simplex<T>>),
+    /// The codimension 1 simplices which are faces of more than two top dimensional simplices.
+    Branching(Vec<Simplex<T>>),
+    /// The smallest top dimensional simplex of each strongly connected component, where two top
+    /// dimensional simplices are in the same component iff they are joined by a path of top
+    /// dimensional simplices meeting in codimension 1 faces.
+    NotStronglyConnected(Vec<Simplex<T>>),
+    /// The vertices whose links are neither spheres nor balls.
+    BadLink(Vec<T>),
+    /// Manifolds can only be recognised up to dimension 3.
+    UnsupportedDimension(usize),
+}
+
+impl<T: Debug> Display for ManifoldError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifoldError::NotPure(s) => write!(f, "maximal simplices of lower dimension: {:?}", vertices(s)),
+            ManifoldError::Branching(s) => write!(f, "faces of more than two top dimensional simplices: {:?}", vertices(s)),
+            ManifoldError::NotStronglyConnected(s) => write!(f, "{:?} are in different strongly connected components", vertices(s)),
+            ManifoldError::BadLink(v) => write!(f, "vertices whose links are neither spheres nor balls: {:?}", v),
+            ManifoldError::UnsupportedDimension(n) => write!(f, "cannot recognise manifolds of dimension {}", n),
+        }
+    }
+}
+
+impl<T: Debug> Error for ManifoldError<T> {}
+
+fn vertices<T>(simplices: &[Simplex<T>]) -> Vec<&BTreeSet<T>> {
+    simplices.iter().map(|s| &s.vertices).collect()
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// Checks that every maximal simplex has the same dimension as the complex.
+    pub fn check_pure(&self) -> Result<(), ManifoldError<T>> {
+        let n = self.dim();
+        let lower: Vec<_> = FacetComplex::from(self).facets().iter()
+            .filter(|s| s.dim() != n)
+            .cloned()
+            .collect();
+        if lower.is_empty() { Ok(()) } else { Err(ManifoldError::NotPure(lower)) }
+    }
+
+    pub fn is_pure(&self) -> bool {
+        self.check_pure().is_ok()
+    }
+
+    /// Checks that any two top dimensional simplices are joined by a path of top dimensional
+    /// simplices, where consecutive simplices meet in a codimension 1 face.
+    pub fn check_strongly_connected(&self) -> Result<(), ManifoldError<T>> {
+        let top = self.dim_simplices(self.dim());
+        let cofaces = self.top_cofaces();
+        let mut seen = BTreeSet::new();
+        let mut components = vec![];
+        // Going through the simplices in order, each one not yet seen is the smallest of a new
+        // component.
+        for first in &top {
+            if !seen.insert(first) {
+                continue;
+            }
+            components.push(first.clone());
+            let mut stack = vec![first];
+            while let Some(s) = stack.pop() {
+                for t in s.faces().flat_map(|f| &cofaces[&f]) {
+                    if seen.insert(t) {
+                        stack.push(t);
+                    }
+                }
+            }
+        }
+        if components.len() <= 1 { Ok(()) } else { Err(ManifoldError::NotStronglyConnected(components)) }
+    }
+
+    pub fn is_strongly_connected(&self) -> bool {
+        self.check_strongly_connected().is_ok()
+    }
+
+    /// Checks that the complex is a pseudomanifold, possibly with boundary, ie. that it is pure
+    /// and strongly connected, and that every codimension 1 simplex is a face of at most two top
+    /// dimensional simplices.
+    pub fn check_pseudomanifold(&self) -> Result<(), ManifoldError<T>> {
+        self.check_pure()?;
+        let branching: Vec<_> = self.top_cofaces().into_iter()
+            .filter(|(_, c)| c.len() > 2)
+            .map(|(s, _)| s)
+            .collect();
+        if !branching.is_empty() {
+            return Err(ManifoldError::Branching(branching));
+        }
+        self.check_strongly_connected()
+    }
+
+    pub fn is_pseudomanifold(&self) -> bool {
+        self.check_pseudomanifold().is_ok()
+    }
+
+    /// The boundary of a pseudomanifold, ie. the subcomplex of the codimension 1 simplices which
+    /// are faces of exactly one top dimensional simplex. It is empty iff the pseudomanifold is
+    /// closed.
+    pub fn boundary_subcomplex(&self) -> SimplicialComplex<T> {
+        SimplicialComplex::closure(self.top_cofaces().into_iter()
+            .filter(|(_, c)| c.len() == 1)
+            .map(|(s, _)| s))
+    }
+
+    /// Checks that the complex is a combinatorial manifold of dimension at most 3, possibly with
+    /// boundary, ie. that it is pure and the link of every vertex is a combinatorial sphere or
+    /// ball of one dimension lower.
+    ///
+    /// Up to dimension 2, spheres and balls are recognised by checking their links recursively,
+    /// and then using the classification of curves and surfaces: a connected closed n-manifold
+    /// with the Euler characteristic of S^n is a sphere when n <= 2, and a connected 2-manifold
+    /// with non-empty boundary and Euler characteristic 1 is a disk.
+    pub fn check_manifold(&self) -> Result<(), ManifoldError<T>> {
+        let n = self.dim();
+        if n > 3 {
+            return Err(ManifoldError::UnsupportedDimension(n));
+        }
+        self.check_pure()?;
+        if n == 0 {
+            return Ok(());
+        }
+        let bad: Vec<T> = self.vertices.iter()
+            .filter(|v| self.link(&vec![(*v).clone()].into()).sphere_or_ball(n - 1).is_none())
+            .cloned()
+            .collect();
+        if bad.is_empty() { Ok(()) } else { Err(ManifoldError::BadLink(bad)) }
+    }
+
+    pub fn is_manifold(&self) -> bool {
+        self.check_manifold().is_ok()
+    }
+
+    /// Some(true) if the complex is a combinatorial n-sphere, Some(false) if it is a combinatorial
+    /// n-ball, and None otherwise, for n <= 2.
+    fn sphere_or_ball(&self, n: usize) -> Option<bool> {
+        if self.simplices.is_empty() || self.dim() != n {
+            return None;
+        }
+        if n == 0 {
+            return match self.vertices.len() {
+                1 => Some(false),
+                2 => Some(true),
+                _ => None,
+            };
+        }
+        if !self.is_pure() || !self.is_strongly_connected() {
+            return None;
+        }
+        for v in &self.vertices {
+            self.link(&vec![v.clone()].into()).sphere_or_ball(n - 1)?;
+        }
+        let sphere = if n.is_multiple_of(2) { 2 } else { 0 };
+        match (self.boundary_subcomplex().simplices.is_empty(), self.euler()) {
+            (true, e) if e == sphere => Some(true),
+            (false, 1) => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The top dimensional simplices containing each codimension 1 simplex.
+    pub(crate) fn top_cofaces(&self) -> BTreeMap<Simplex<T>, Vec<&Simplex<T>>> {
+        let n = self.dim();
+        let mut cofaces: BTreeMap<Simplex<T>, Vec<&Simplex<T>>> = BTreeMap::new();
+        if n > 0 {
+            for s in self.dim_simplices(n - 1) {
+                cofaces.insert(s, vec![]);
+            }
+        }
+        for s in self.simplices.iter().filter(|s| s.dim() == n) {
+            for f in s.faces() {
+                cofaces.entry(f).or_default().push(s);
+            }
+        }
+        cofaces
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+
+use super::chain::Chain;
+use super::manifold::ManifoldError;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+/// A coherent orientation of a pseudomanifold, given by a sign for each top dimensional simplex,
+/// where +1 means the orientation given by the order on its vertices. Coherent means that every
+/// codimension 1 simplex which is a face of two top dimensional simplices gets opposite
+/// orientations from them, with the signs given by `Simplex::boundary_coeff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Orientation<T> {
+    signs: BTreeMap<Simplex<T>, i64>,
+}
+
+/// The reasons `SimplicialComplex::orient` can fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrientationError<T> {
+    /// Only pseudomanifolds can be oriented.
+    NotPseudomanifold(ManifoldError<T>),
+    /// A loop of top dimensional simplices, each meeting the next in a codimension 1 face and the
+    /// last meeting the first, along which the orientation is reversed. This proves that the
+    /// complex is not orientable.
+    NonOrientable(Vec<Simplex<T>>),
+}
+
+impl<T: Debug> Display for OrientationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrientationError::NotPseudomanifold(e) => write!(f, "not a pseudomanifold: {}", e),
+            OrientationError::NonOrientable(l) => {
+                let l: Vec<_> = l.iter().map(|s| &s.vertices).collect();
+                write!(f, "the orientation is reversed along {:?}", l)
+            },
+        }
+    }
+}
+
+impl<T: Debug> Error for OrientationError<T> {}
+
+impl<T> Orientation<T> where T: Ord + Clone {
+    /// The sign of a top dimensional simplex.
+    pub fn sign(&self, s: &Simplex<T>) -> Option<i64> {
+        self.signs.get(s).copied()
+    }
+
+    /// The sum of the top dimensional simplices, each with its sign.
+    pub fn chain(&self) -> Chain<T, i64> {
+        self.signs.iter()
+            .map(|(s, &c)| (s.clone(), c))
+            .collect()
+    }
+
+    /// The fundamental class, which is the chain of the orientation when it is a cycle, ie. when
+    /// the pseudomanifold is closed. It generates the top integral homology.
+    pub fn fundamental_class(&self) -> Option<Chain<T, i64>> {
+        let c = self.chain();
+        if c.is_cycle() { Some(c) } else { None }
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// Find a coherent orientation of a pseudomanifold, or a loop of simplices along which the
+    /// orientation is reversed if there is none.
+    ///
+    /// Starting from any top dimensional simplex, the orientation is spread to its neighbours
+    /// across codimension 1 faces. If a neighbour has already been given the opposite sign, then
+    /// the two paths to it from the start make up an orientation reversing loop.
+    pub fn orient(&self) -> Result<Orientation<T>, OrientationError<T>> {
+        self.check_pseudomanifold().map_err(OrientationError::NotPseudomanifold)?;
+        let n = self.dim();
+        let cofaces = self.top_cofaces();
+        let mut signs: BTreeMap<&Simplex<T>, i64> = BTreeMap::new();
+        let mut parent: BTreeMap<&Simplex<T>, &Simplex<T>> = BTreeMap::new();
+        let mut queue = VecDeque::new();
+        if let Some(first) = self.simplices.iter().find(|s| s.dim() == n) {
+            signs.insert(first, 1);
+            queue.push_back(first);
+        }
+        while let Some(s) = queue.pop_front() {
+            for f in s.faces() {
+                for &t in cofaces[&f].iter().filter(|&&t| t != s) {
+                    // t must induce the opposite orientation on f to s.
+                    let sign = -signs[s] * s.boundary_coeff::<i64>(&f) * t.boundary_coeff::<i64>(&f);
+                    match signs.get(t) {
+                        None => {
+                            signs.insert(t, sign);
+                            parent.insert(t, s);
+                            queue.push_back(t);
+                        },
+                        Some(&x) if x != sign => {
+                            return Err(OrientationError::NonOrientable(reversing_loop(&parent, s, t)));
+                        },
+                        _ => {},
+                    }
+                }
+            }
+        }
+        Ok(Orientation {
+            signs: signs.into_iter().map(|(s, c)| (s.clone(), c)).collect(),
+        })
+    }
+
+    pub fn is_orientable(&self) -> bool {
+        self.orient().is_ok()
+    }
+}
+
+/// The loop going from s back to the root of the search tree and then out to t, where s and t
+/// are adjacent.
+fn reversing_loop<T: Ord + Clone>(parent: &BTreeMap<&Simplex<T>, &Simplex<T>>, s: &Simplex<T>, t: &Simplex<T>) -> Vec<Simplex<T>> {
+    let ancestors = |mut x| {
+        let mut path = vec![x];
+        while let Some(&p) = parent.get(x) {
+            path.push(p);
+            x = p;
+        }
+        path
+    };
+    let mut from_s = ancestors(s);
+    let mut from_t = ancestors(t);
+    // Cut the two paths off at their last common simplex.
+    while from_s.len() > 1 && from_t.len() > 1 && from_s[from_s.len() - 2] == from_t[from_t.len() - 2] {
+        from_s.pop();
+        from_t.pop();
+    }
+    from_t.pop();
+    from_s.into_iter()
+        .chain(from_t.into_iter().rev())
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,48 @@
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::facet_complex::FacetComplex;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The cartesian product of two complexes, triangulated using the order on the vertices.
+    ///
+    /// The product of a p-simplex a_0 < ... < a_p and a q-simplex b_0 < ... < b_q is triangulated
+    /// by the staircase (p+q)-simplices, given by the monotone lattice paths from (a_0, b_0) to
+    /// (a_p, b_q) which take one step in either direction at a time. The simplices of the product
+    /// are the faces of the staircases of every pair of facets.
+    pub fn product<U: Ord + Clone>(&self, other: &SimplicialComplex<U>) -> SimplicialComplex<(T, U)> {
+        let left = FacetComplex::from(self);
+        let right = FacetComplex::from(other);
+        let mut staircases = vec![];
+        for s in left.facets() {
+            for t in right.facets() {
+                let a: Vec<T> = s.vertices.iter().cloned().collect();
+                let b: Vec<U> = t.vertices.iter().cloned().collect();
+                staircases.extend(lattice_paths(a.len() - 1, b.len() - 1).into_iter()
+                    .map(|path| path.into_iter()
+                        .map(|(i, j)| (a[i].clone(), b[j].clone()))
+                        .collect::<Simplex<_>>()));
+            }
+        }
+        SimplicialComplex::closure(staircases)
+    }
+}
+
+/// All monotone lattice paths from (0, 0) to (p, q), as lists of the points they pass through.
+pub(crate) fn lattice_paths(p: usize, q: usize) -> Vec<Vec<(usize, usize)>> {
+    fn go(i: usize, j: usize, p: usize, q: usize, path: &mut Vec<(usize, usize)>, out: &mut Vec<Vec<(usize, usize)>>) {
+        path.push((i, j));
+        if i == p && j == q {
+            out.push(path.clone());
+        }
+        if i < p {
+            go(i + 1, j, p, q, path, out);
+        }
+        if j < q {
+            go(i, j + 1, p, q, path, out);
+        }
+        path.pop();
+    }
+    let mut out = vec![];
+    go(0, 0, p, q, &mut vec![], &mut out);
+    out
+}
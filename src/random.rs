@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use nalgebra::{DMatrix, DVector};
+use rand::Rng;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::facet_complex::FacetComplex;
+use super::vietoris_rips::{vietoris_rips_simplicial_complex, vietoris_rips_skeleton, vr_simplicial_complex};
+
+/// The Linial-Meshulam random k-complex Y_k(n, p), which has the full (k-1)-skeleton of the
+/// simplex on n vertices, and each k-simplex independently with probability p.
+pub fn linial_meshulam<R: Rng + ?Sized>(n: usize, k: usize, p: f64, rng: &mut R) -> SimplicialComplex<usize> {
+    if n == 0 {
+        return SimplicialComplex::new(BTreeSet::new());
+    }
+    // Only the subsets of at most k + 1 vertices are generated, rather than all 2^n faces.
+    let simplex = FacetComplex::new(vec![(0..n).collect()]);
+    let mut simplices: BTreeSet<Simplex<usize>> = (0..k)
+        .flat_map(|j| simplex.dim_simplices(j))
+        .collect();
+    simplices.extend(simplex.dim_simplices(k).into_iter()
+        .filter(|_| rng.gen_bool(p)));
+    SimplicialComplex::new(simplices)
+}
+
+/// The Erdős-Rényi random graph G(n, p), as an adjacency matrix.
+fn erdos_renyi<R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> DMatrix<bool> {
+    let mut adj = DMatrix::from_element(n, n, false);
+    for i in 0..n {
+        adj[(i, i)] = true;
+        for j in (i + 1)..n {
+            let e = rng.gen_bool(p);
+            adj[(i, j)] = e;
+            adj[(j, i)] = e;
+        }
+    }
+    adj
+}
+
+/// The clique complex of the Erdős-Rényi random graph G(n, p), ie. the complex whose simplices
+/// are the cliques of a graph on n vertices where each edge is included independently with
+/// probability p.
+pub fn random_clique_complex<R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> SimplicialComplex<usize> {
+    vr_simplicial_complex(&erdos_renyi(n, p, rng), usize::MAX)
+}
+
+/// n points sampled uniformly from the unit cube [0, 1]^dim, one per row.
+fn uniform_points<R: Rng + ?Sized>(n: usize, dim: usize, rng: &mut R) -> DMatrix<f64> {
+    DMatrix::from_fn(n, dim, |_, _| rng.gen())
+}
+
+fn distance_matrix(points: &DMatrix<f64>) -> DMatrix<f64> {
+    let n = points.nrows();
+    DMatrix::from_fn(n, n, |i, j| (points.row(i) - points.row(j)).norm())
+}
+
+/// The Vietoris-Rips complex at scale epsilon of n points sampled uniformly from [0, 1]^dim.
+pub fn random_vietoris_rips<R: Rng + ?Sized>(n: usize, dim: usize, epsilon: f64, rng: &mut R) -> SimplicialComplex<usize> {
+    let points = uniform_points(n, dim, rng);
+    vietoris_rips_simplicial_complex(&distance_matrix(&points), epsilon)
+}
+
+/// The Čech complex at radius r of n points sampled uniformly from [0, 1]^dim, up to dimension
+/// max_dim. A set of points is a simplex iff the balls of radius r around them have a common
+/// point, ie. iff their smallest enclosing ball has radius at most r.
+pub fn random_cech<R: Rng + ?Sized>(n: usize, dim: usize, r: f64, max_dim: usize, rng: &mut R) -> SimplicialComplex<usize> {
+    let points = uniform_points(n, dim, rng);
+    // Every simplex of the Čech complex is a simplex of the Vietoris-Rips complex at scale 2r.
+    let vr = vietoris_rips_skeleton(&distance_matrix(&points), 2. * r, max_dim);
+    SimplicialComplex::new(vr.simplices.into_iter()
+        .filter(|s| {
+            let p: Vec<DVector<f64>> = s.vertices.iter()
+                .map(|&i| points.row(i).transpose())
+                .collect();
+            enclosing_radius(&p) <= r + 1e-12
+        })
+        .collect())
+}
+
+/// The radius of the smallest ball containing the points. This ball is the circumscribed ball
+/// of some subset of the points, so for the few points of a simplex we can try every subset.
+fn enclosing_radius(points: &[DVector<f64>]) -> f64 {
+    let n = points.len();
+    (1..(1usize << n))
+        .filter_map(|mask| {
+            let subset: Vec<&DVector<f64>> = (0..n)
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| &points[i])
+                .collect();
+            let centre = circumcentre(&subset)?;
+            let radius = subset.iter()
+                .map(|p| (*p - &centre).norm())
+                .fold(0., f64::max);
+            if points.iter().all(|p| (p - &centre).norm() <= radius + 1e-12) {
+                Some(radius)
+            } else {
+                None
+            }
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// The centre of the smallest sphere through the points, ie. the one whose centre lies in their
+/// affine hull. Returns None if the points are affinely dependent.
+fn circumcentre(points: &[&DVector<f64>]) -> Option<DVector<f64>> {
+    let p0 = points[0];
+    if points.len() == 1 {
+        return Some(p0.clone());
+    }
+    let v: Vec<DVector<f64>> = points[1..].iter().map(|p| *p - p0).collect();
+    let k = v.len();
+    // The centre is p0 + sum_i l_i v_i, where 2 v_j . (sum_i l_i v_i) = |v_j|^2 for each j.
+    let gram = DMatrix::from_fn(k, k, |i, j| 2. * v[i].dot(&v[j]));
+    let b = DVector::from_fn(k, |i, _| v[i].norm_squared());
+    let l = gram.lu().solve(&b)?;
+    Some(v.iter()
+        .zip(l.iter())
+        .fold(p0.clone(), |c, (vi, li)| c + vi * *li))
+}
+
+/// A random subcomplex of the simplex on n + 1 vertices, ie. `SimplicialComplex::solid(n)`. Going
+/// up in dimension, each simplex whose faces have all been included is included independently
+/// with probability p.
+pub fn random_subcomplex<R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> SimplicialComplex<usize> {
+    let mut level: Vec<Simplex<usize>> = (0..=n)
+        .map(|v| Simplex::from(vec![v]))
+        .filter(|_| rng.gen_bool(p))
+        .collect();
+    let mut simplices: BTreeSet<Simplex<usize>> = BTreeSet::new();
+    while !level.is_empty() {
+        simplices.extend(level.iter().cloned());
+        // Each candidate is generated once, from the face without its largest vertex, which must
+        // have been included, and then the rest of its faces are checked.
+        level = level.iter()
+            .flat_map(|s| {
+                let top = *s.vertices.iter().next_back().unwrap();
+                (top + 1..=n).map(move |v| s.add_vertex(v))
+            })
+            .filter(|s| s.faces().all(|t| simplices.contains(&t)))
+            .filter(|_| rng.gen_bool(p))
+            .collect();
+    }
+    SimplicialComplex::new(simplices)
+}
@@ -0,0 +1,37 @@
+use super::simplicial_complex::SimplicialComplex;
+use super::boundary::rank;
+use nalgebra::DMatrix;
+use num_traits::Num;
+use std::fmt::Debug;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// Compute the k-th boundary map of the relative chain complex C(K) / C(L), where K is self
+    /// and L is a subcomplex. This is the boundary map of K with the rows and columns of the
+    /// simplices of L removed.
+    pub fn relative_boundary<U: 'static + Num + Clone + Debug>(&self, k: usize, sub: &SimplicialComplex<T>) -> DMatrix<U> {
+        if !sub.is_subcomplex(self) {
+            panic!("Cannot compute relative homology with respect to a complex which is not a subcomplex");
+        }
+        let mut ck = self.dim_simplices(k);
+        ck.retain(|s| !sub.simplices.contains(s));
+        if k == 0 {
+            return DMatrix::zeros(0, ck.len());
+        }
+        let mut ck1 = self.dim_simplices(k - 1);
+        ck1.retain(|s| !sub.simplices.contains(s));
+        let n = ck.len();
+        let m = ck1.len();
+        let i = ck.into_iter()
+            .flat_map(|s| ck1.iter()
+                .map(move |t| s.boundary_coeff(t)));
+        DMatrix::from_iterator(m, n, i)
+    }
+
+    /// The k-th relative Betti number, ie. the rank of H_k(K, L), where K is self and L is a
+    /// subcomplex.
+    pub fn relative_betti(&self, k: usize, sub: &SimplicialComplex<T>) -> usize {
+        let dk = self.relative_boundary::<f64>(k, sub);
+        let dk1 = self.relative_boundary::<f64>(k + 1, sub);
+        dk.ncols() - (rank(&dk) + rank(&dk1))
+    }
+}
@@ -50,6 +50,17 @@ impl<T> Simplex<T> where T: Ord + Clone {
         b.insert(v);
         Simplex::new(b)
     }
+
+    /// The codimension 1 faces of a simplex, ie. the simplex with one vertex removed. A 0-simplex
+    /// has no faces, as we do not count the empty simplex.
+    pub fn faces(&self) -> impl Iterator<Item = Simplex<T>> + '_ {
+        self.vertices.iter()
+            .filter(move |_| self.vertices.len() > 1)
+            .map(move |v| self.vertices.iter()
+                .filter(|&w| w != v)
+                .cloned()
+                .collect())
+    }
 }
 
 impl<T> From<Vec<T>> for Simplex<T> where T: Ord + Clone {
@@ -0,0 +1,213 @@
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::filtration::Filtration;
+
+/// A node of a simplex tree. The path of labels from the root to a node is the (sorted) list of
+/// vertices of the simplex it represents.
+#[derive(Clone, Debug, PartialEq)]
+struct Node<T> {
+    filtration: f64,
+    children: BTreeMap<T, Node<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(filtration: f64) -> Self {
+        Node { filtration, children: BTreeMap::new() }
+    }
+}
+
+/// A simplex tree, ie. a trie of simplices keyed by their vertices in increasing order.
+///
+/// Every simplex is stored as a single node, and the tree is always closed under taking faces, so
+/// it always represents a simplicial complex. Each simplex also carries a filtration value, which
+/// is never smaller than the filtration value of any of its faces. This is how a `Filtration` is
+/// stored, so each simplex is only stored once rather than once for every complex containing it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimplexTree<T> {
+    root: BTreeMap<T, Node<T>>,
+    /// The number of simplices of each dimension.
+    counts: Vec<usize>,
+}
+
+impl<T> Default for SimplexTree<T> where T: Ord + Clone {
+    fn default() -> Self {
+        SimplexTree::new()
+    }
+}
+
+impl<T> SimplexTree<T> where T: Ord + Clone {
+    pub fn new() -> Self {
+        SimplexTree { root: BTreeMap::new(), counts: vec![] }
+    }
+
+    /// Insert a simplex and all of its faces, with filtration value 0.
+    pub fn insert(&mut self, s: &Simplex<T>) {
+        self.insert_with_filtration(s, 0.);
+    }
+
+    /// Insert a simplex and all of its faces with the given filtration value. If a face is already
+    /// in the tree with a larger filtration value, its value is lowered to `filtration`.
+    pub fn insert_with_filtration(&mut self, s: &Simplex<T>, filtration: f64) {
+        let vertices: Vec<T> = s.vertices.iter().cloned().collect();
+        if self.counts.len() < vertices.len() {
+            self.counts.resize(vertices.len(), 0);
+        }
+        // Every face of s is a subsequence of its vertices, so we insert each of the suffixes
+        // below each node along the way.
+        fn go<T: Ord + Clone>(children: &mut BTreeMap<T, Node<T>>, vertices: &[T], depth: usize, filtration: f64, counts: &mut [usize]) {
+            for (i, v) in vertices.iter().enumerate() {
+                let node = match children.entry(v.clone()) {
+                    Entry::Occupied(e) => e.into_mut(),
+                    Entry::Vacant(e) => {
+                        counts[depth] += 1;
+                        e.insert(Node::new(filtration))
+                    },
+                };
+                if node.filtration > filtration {
+                    node.filtration = filtration;
+                }
+                go(&mut node.children, &vertices[i + 1..], depth + 1, filtration, counts);
+            }
+        }
+        go(&mut self.root, &vertices, 0, filtration, &mut self.counts);
+    }
+
+    fn find(&self, s: &Simplex<T>) -> Option<&Node<T>> {
+        let mut children = &self.root;
+        let mut node = None;
+        for v in &s.vertices {
+            let n = children.get(v)?;
+            children = &n.children;
+            node = Some(n);
+        }
+        node
+    }
+
+    /// Whether the simplex s is in the tree.
+    pub fn contains(&self, s: &Simplex<T>) -> bool {
+        self.find(s).is_some()
+    }
+
+    /// The filtration value of s, if it is in the tree.
+    pub fn filtration(&self, s: &Simplex<T>) -> Option<f64> {
+        self.find(s).map(|n| n.filtration)
+    }
+
+    /// The number of simplices in the tree.
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_empty()
+    }
+
+    /// The number of k-simplices in the tree.
+    pub fn count(&self, k: usize) -> usize {
+        self.counts.get(k).copied().unwrap_or(0)
+    }
+
+    /// The dimension of the complex, ie. the depth of the tree minus 1.
+    pub fn dim(&self) -> usize {
+        self.counts.len().saturating_sub(1)
+    }
+
+    /// Visit every simplex in the tree in lexicographic order, along with its filtration value.
+    fn visit<F: FnMut(&[T], f64)>(&self, f: &mut F) {
+        fn go<T: Clone, F: FnMut(&[T], f64)>(children: &BTreeMap<T, Node<T>>, path: &mut Vec<T>, f: &mut F) {
+            for (v, n) in children {
+                path.push(v.clone());
+                f(path, n.filtration);
+                go(&n.children, path, f);
+                path.pop();
+            }
+        }
+        go(&self.root, &mut vec![], f);
+    }
+
+    /// All simplices in the tree, in lexicographic order.
+    pub fn simplices(&self) -> impl Iterator<Item = Simplex<T>> {
+        let mut out = vec![];
+        self.visit(&mut |path, _| out.push(path.iter().cloned().collect()));
+        out.into_iter()
+    }
+
+    /// Returns the set of k-simplices in the tree.
+    pub fn dim_simplices(&self, k: usize) -> BTreeSet<Simplex<T>> {
+        let mut out = BTreeSet::new();
+        self.visit(&mut |path, _| if path.len() == k + 1 {
+            out.insert(path.iter().cloned().collect());
+        });
+        out
+    }
+
+    /// The codimension 1 faces of s in the tree. As the tree is closed under taking faces, these
+    /// are all the faces of s if s is in the tree, and there are none otherwise.
+    pub fn faces(&self, s: &Simplex<T>) -> impl Iterator<Item = Simplex<T>> {
+        let faces: Vec<_> = if self.contains(s) {
+            s.faces().collect()
+        } else {
+            vec![]
+        };
+        faces.into_iter()
+    }
+
+    /// All simplices in the tree which have s as a proper face.
+    pub fn cofaces(&self, s: &Simplex<T>) -> impl Iterator<Item = Simplex<T>> {
+        fn go<T: Ord + Clone>(children: &BTreeMap<T, Node<T>>, remaining: &[T], path: &mut Vec<T>, out: &mut Vec<Simplex<T>>) {
+            for (v, n) in children {
+                let rest = match remaining.first() {
+                    // Vertices are sorted, so once we have passed the next vertex of s there are
+                    // no more cofaces below this node.
+                    Some(w) if v > w => break,
+                    Some(w) if v == w => &remaining[1..],
+                    _ => remaining,
+                };
+                path.push(v.clone());
+                if rest.is_empty() {
+                    out.push(path.iter().cloned().collect());
+                }
+                go(&n.children, rest, path, out);
+                path.pop();
+            }
+        }
+        let vertices: Vec<T> = s.vertices.iter().cloned().collect();
+        let mut out = vec![];
+        go(&self.root, &vertices, &mut vec![], &mut out);
+        out.retain(|t| t.vertices.len() > s.vertices.len());
+        out.into_iter()
+    }
+
+    /// The simplicial complex represented by this tree.
+    pub fn to_complex(&self) -> SimplicialComplex<T> {
+        SimplicialComplex::new(self.simplices().collect())
+    }
+
+    /// The subcomplex of all simplices with filtration value at most `filtration`.
+    pub fn sublevel_complex(&self, filtration: f64) -> SimplicialComplex<T> {
+        let mut out = BTreeSet::new();
+        self.visit(&mut |path, f| if f <= filtration {
+            out.insert(path.iter().cloned().collect());
+        });
+        SimplicialComplex::new(out)
+    }
+
+    /// The filtration given by the sublevel complexes at each of the given values, which must be
+    /// increasing. The complexes are not built until they are asked for.
+    pub fn to_filtration(&self, filtrations: &[f64]) -> Filtration<T> {
+        Filtration::from_tree(self.clone(), filtrations.to_vec())
+    }
+}
+
+impl<T> From<&SimplicialComplex<T>> for SimplexTree<T> where T: Ord + Clone {
+    fn from(k: &SimplicialComplex<T>) -> Self {
+        let mut tree = SimplexTree::new();
+        for s in &k.simplices {
+            tree.insert(s);
+        }
+        tree
+    }
+}
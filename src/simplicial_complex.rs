@@ -1,6 +1,8 @@
 use std::collections::BTreeSet;
 use super::simplex::Simplex;
 use std::iter::FromIterator;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SimplicialComplex<T> {
@@ -8,16 +10,75 @@ pub struct SimplicialComplex<T> {
     pub simplices: BTreeSet<Simplex<T>>
 }
 
-impl<T> SimplicialComplex<T> where T: Ord + Copy {
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
     pub fn new(simplices: BTreeSet<Simplex<T>>) -> Self {
         let vertices = simplices.iter()
-            .map(|s| &s.vertices)
-            .flatten()
-            .copied()
+            .flat_map(|s| &s.vertices)
+            .cloned()
             .collect();
         SimplicialComplex { vertices, simplices }
     }
 
+    /// Construct a simplicial complex, checking that every face of every simplex is also in the
+    /// set. Otherwise, the missing faces are returned.
+    pub fn try_new(simplices: BTreeSet<Simplex<T>>) -> Result<Self, MissingFaces<T>> {
+        let k = SimplicialComplex::new(simplices);
+        let missing = k.missing_faces();
+        if missing.is_empty() {
+            Ok(k)
+        } else {
+            Err(MissingFaces { missing })
+        }
+    }
+
+    /// The smallest simplicial complex containing the given simplices, ie. the set of all of their
+    /// faces. In particular, a complex can be given by its maximal simplices only.
+    pub fn closure<I: IntoIterator<Item = Simplex<T>>>(simplices: I) -> Self {
+        let mut stack: Vec<_> = simplices.into_iter().collect();
+        let mut closed = BTreeSet::new();
+        while let Some(s) = stack.pop() {
+            if !closed.contains(&s) {
+                stack.extend(s.faces());
+                closed.insert(s);
+            }
+        }
+        SimplicialComplex::new(closed)
+    }
+
+    /// The faces of simplices in the complex which are not themselves in the complex, ie. the
+    /// closure of the simplices minus the simplices. This is empty iff the complex is closed under
+    /// taking faces.
+    pub fn missing_faces(&self) -> BTreeSet<Simplex<T>> {
+        // Only the faces of missing simplices need to be searched, as the faces of simplices in
+        // the complex are searched from there.
+        let mut stack: Vec<_> = self.simplices.iter()
+            .flat_map(|s| s.faces())
+            .filter(|t| !self.simplices.contains(t))
+            .collect();
+        let mut missing = BTreeSet::new();
+        while let Some(s) = stack.pop() {
+            if !missing.contains(&s) {
+                stack.extend(s.faces().filter(|t| !self.simplices.contains(t)));
+                missing.insert(s);
+            }
+        }
+        missing
+    }
+
+    /// Relabel the vertices of the complex by f. If f is not injective, simplices whose vertices
+    /// are identified are mapped to lower dimensional simplices.
+    pub fn map_vertices<U: Ord + Clone, F: Fn(T) -> U>(&self, f: F) -> SimplicialComplex<U> {
+        SimplicialComplex::new(self.simplices.iter()
+            .map(|s| s.vertices.iter().map(|v| f(v.clone())).collect())
+            .collect())
+    }
+
+    /// Whether the set of simplices is closed under taking faces.
+    pub fn is_valid(&self) -> bool {
+        self.simplices.iter()
+            .all(|s| s.faces().all(|t| self.simplices.contains(&t)))
+    }
+
     /// Returns the set of k-simplices in the complex.
     pub fn dim_simplices(&self, k: usize) -> BTreeSet<Simplex<T>> {
         self.simplices.iter()
@@ -37,14 +98,32 @@ impl<T> SimplicialComplex<T> where T: Ord + Copy {
     /// Euler characteristic of a simplicial complex is the alternating sum of the 
     /// number of simplices in each dimension.
     pub fn euler(&self) -> isize {
-        (0..=self.dim()).enumerate()
-            .map(|(i, dim)| (-1isize).pow(i as u32) * 
-                self.dim_simplices(dim).len() as isize)
+        self.simplices.iter()
+            .map(|s| if s.dim() % 2 == 0 { 1 } else { -1 })
             .sum()
     }
 }
 
-impl<T> From<Vec<Vec<T>>> for SimplicialComplex<T> where T: Ord + Copy {
+/// The error returned by `SimplicialComplex::try_new` if the simplices are not closed under
+/// taking faces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingFaces<T> {
+    pub missing: BTreeSet<Simplex<T>>,
+}
+
+impl<T: Debug> Display for MissingFaces<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "faces missing from simplicial complex: ")?;
+        let faces: Vec<_> = self.missing.iter()
+            .map(|s| &s.vertices)
+            .collect();
+        write!(f, "{:?}", faces)
+    }
+}
+
+impl<T: Debug> Error for MissingFaces<T> {}
+
+impl<T> From<Vec<Vec<T>>> for SimplicialComplex<T> where T: Ord + Clone {
     fn from(v: Vec<Vec<T>>) -> Self {
         SimplicialComplex::new(v.into_iter()
             .map(|x| x.into())
@@ -72,8 +151,6 @@ impl SimplicialComplex<usize> {
         if k == 0 {
             panic!("Cannot make a hollow simplicial complex of dimension 0");
         }
-        let mut s = SimplicialComplex::solid(k);
-        s.simplices.remove(&Simplex::from_iter(0..=(k)));
-        s
+        SimplicialComplex::solid(k).skeleton(k - 1)
     }
 }
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+
+use nalgebra::DMatrix;
+use num_traits::Num;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::chain_map::ChainMap;
+use super::linear_algebra::coefficients::Coefficients;
+
+pub use super::chain_map::InducedMap;
+
+/// A simplicial map between two complexes, given by a map on vertices which sends every simplex
+/// of the domain to a simplex of the codomain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimplicialMap<S, T> {
+    domain: SimplicialComplex<S>,
+    codomain: SimplicialComplex<T>,
+    map: BTreeMap<S, T>,
+}
+
+/// The reasons a vertex map can fail to be a simplicial map.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimplicialMapError<S> {
+    /// A vertex of the domain which is not mapped anywhere.
+    MissingVertex(S),
+    /// A vertex of the domain which is mapped to a vertex which is not in the codomain.
+    BadVertex(S),
+    /// A simplex of the domain whose image is not a simplex of the codomain.
+    NotSimplicial(Simplex<S>),
+}
+
+impl<S: Debug> Display for SimplicialMapError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimplicialMapError::MissingVertex(v) => write!(f, "vertex {:?} is not mapped", v),
+            SimplicialMapError::BadVertex(v) => write!(f, "vertex {:?} is not mapped into the codomain", v),
+            SimplicialMapError::NotSimplicial(s) => write!(f, "the image of {:?} is not a simplex", s.vertices),
+        }
+    }
+}
+
+impl<S: Debug> Error for SimplicialMapError<S> {}
+
+impl<S, T> SimplicialMap<S, T> where S: Ord + Clone, T: Ord + Clone {
+    /// Construct a simplicial map from a map on vertices, checking that every vertex of the
+    /// domain is mapped into the codomain, and that every simplex is mapped to a simplex.
+    pub fn new(domain: SimplicialComplex<S>, codomain: SimplicialComplex<T>, map: BTreeMap<S, T>) -> Result<Self, SimplicialMapError<S>> {
+        for v in &domain.vertices {
+            match map.get(v) {
+                None => return Err(SimplicialMapError::MissingVertex(v.clone())),
+                Some(w) if !codomain.vertices.contains(w) => return Err(SimplicialMapError::BadVertex(v.clone())),
+                _ => {},
+            }
+        }
+        let f = SimplicialMap { domain, codomain, map };
+        if let Some(s) = f.domain.simplices.iter()
+            .find(|s| !f.codomain.simplices.contains(&f.apply(s))) {
+            return Err(SimplicialMapError::NotSimplicial(s.clone()));
+        }
+        Ok(f)
+    }
+
+    /// Construct a simplicial map from a function on the vertices of the domain.
+    pub fn from_fn<F: Fn(&S) -> T>(domain: SimplicialComplex<S>, codomain: SimplicialComplex<T>, f: F) -> Result<Self, SimplicialMapError<S>> {
+        let map = domain.vertices.iter()
+            .map(|v| (v.clone(), f(v)))
+            .collect();
+        SimplicialMap::new(domain, codomain, map)
+    }
+
+    pub fn domain(&self) -> &SimplicialComplex<S> {
+        &self.domain
+    }
+
+    pub fn codomain(&self) -> &SimplicialComplex<T> {
+        &self.codomain
+    }
+
+    /// The image of a vertex of the domain.
+    pub fn vertex(&self, v: &S) -> &T {
+        &self.map[v]
+    }
+
+    /// The image of a simplex of the domain, which may have lower dimension.
+    pub fn apply(&self, s: &Simplex<S>) -> Simplex<T> {
+        s.vertices.iter()
+            .map(|v| self.vertex(v).clone())
+            .collect()
+    }
+
+    /// The image of a simplex with the vertices in the order given by the order on its vertices,
+    /// along with the sign of the permutation that sorts them. Returns None if two vertices are
+    /// sent to the same vertex, as then the simplex is degenerate.
+    fn oriented_image(&self, s: &Simplex<S>) -> Option<(Simplex<T>, bool)> {
+        let image: Vec<&T> = s.vertices.iter()
+            .map(|v| self.vertex(v))
+            .collect();
+        let mut inversions = 0;
+        for i in 0..image.len() {
+            for j in (i + 1)..image.len() {
+                if image[i] == image[j] {
+                    return None;
+                }
+                if image[i] > image[j] {
+                    inversions += 1;
+                }
+            }
+        }
+        Some((image.into_iter().cloned().collect(), inversions % 2 == 0))
+    }
+
+    /// Compute the k-th chain map, from the k-chains of the domain to the k-chains of the
+    /// codomain. The bases are ordered in the same way as for `SimplicialComplex::boundary`.
+    /// A simplex whose image is degenerate is sent to 0.
+    pub fn chain_map<U: 'static + Num + Clone + Debug>(&self, k: usize) -> DMatrix<U> {
+        let ck = self.domain.dim_simplices(k);
+        let dk = self.codomain.dim_simplices(k);
+        let index: BTreeMap<&Simplex<T>, usize> = dk.iter()
+            .enumerate()
+            .map(|(i, t)| (t, i))
+            .collect();
+        let mut m = DMatrix::zeros(dk.len(), ck.len());
+        for (j, s) in ck.iter().enumerate() {
+            if let Some((t, even)) = self.oriented_image(s) {
+                m[(index[&t], j)] = if even { U::one() } else { U::zero() - U::one() };
+            }
+        }
+        m
+    }
+
+    /// The induced map on the k-th homology with the given coefficients. Returns None for
+    /// `Coefficients::Integers`, as the ranks would miss the map on the torsion, so use
+    /// `Coefficients::Rationals` for the free part.
+    pub fn induced_map(&self, k: usize, coefficients: Coefficients) -> Option<InducedMap> {
+        ChainMap::from(self).induced_map(k, coefficients)
+    }
+}
+
+/// A simplicial map from a complex to itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimplicialSelfMap<T> {
+    map: SimplicialMap<T, T>,
+}
+
+impl<T> SimplicialSelfMap<T> where T: Ord + Clone {
+    /// Construct a simplicial self-map of a complex from a map on its vertices, with the same
+    /// checks as `SimplicialMap::new`.
+    pub fn new(complex: SimplicialComplex<T>, map: BTreeMap<T, T>) -> Result<Self, SimplicialMapError<T>> {
+        let map = SimplicialMap::new(complex.clone(), complex, map)?;
+        Ok(SimplicialSelfMap { map })
+    }
+
+    /// Construct a simplicial self-map of a complex from a function on its vertices.
+    pub fn from_fn<F: Fn(&T) -> T>(complex: SimplicialComplex<T>, f: F) -> Result<Self, SimplicialMapError<T>> {
+        let map = SimplicialMap::from_fn(complex.clone(), complex, f)?;
+        Ok(SimplicialSelfMap { map })
+    }
+
+    pub fn complex(&self) -> &SimplicialComplex<T> {
+        &self.map.domain
+    }
+
+    /// The underlying simplicial map, for its chain maps and induced maps.
+    pub fn as_map(&self) -> &SimplicialMap<T, T> {
+        &self.map
+    }
+
+    /// The Lefschetz number of the map, ie. the alternating sum of the traces of the induced
+    /// maps on rational homology. By the Hopf trace formula, this is the same as the alternating
+    /// sum of the traces of the chain maps, which is what we compute.
+    ///
+    /// If the Lefschetz number is non-zero, the map has a fixed point.
+    pub fn lefschetz_number(&self) -> i64 {
+        (0..=self.complex().dim())
+            .map(|k| {
+                let t = self.map.chain_map::<i64>(k).trace();
+                if k % 2 == 0 { t } else { -t }
+            })
+            .sum()
+    }
+
+    /// The simplices which are sent to themselves. A simplicial map has a fixed point iff this
+    /// is non-empty, as a point in the interior of a simplex is sent into the interior of its
+    /// image, and a map from a simplex to itself has a fixed point.
+    pub fn invariant_simplices(&self) -> impl Iterator<Item = &Simplex<T>> + '_ {
+        self.complex().simplices.iter()
+            .filter(move |s| self.map.apply(s) == **s)
+    }
+
+    pub fn has_fixed_point(&self) -> bool {
+        self.invariant_simplices().next().is_some()
+    }
+}
+
+impl<T> From<SimplicialSelfMap<T>> for SimplicialMap<T, T> {
+    fn from(f: SimplicialSelfMap<T>) -> Self {
+        f.map
+    }
+}
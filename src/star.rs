@@ -0,0 +1,29 @@
+use std::collections::BTreeSet;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The star of s is the set of simplices which have s as a face, including s itself. This is
+    /// also called the open star, and is not a simplicial complex in general.
+    pub fn star(&self, s: &Simplex<T>) -> BTreeSet<Simplex<T>> {
+        self.simplices.iter()
+            .filter(|t| s.is_face(t))
+            .cloned()
+            .collect()
+    }
+
+    /// The closed star of s is the smallest subcomplex containing the star of s.
+    pub fn closed_star(&self, s: &Simplex<T>) -> SimplicialComplex<T> {
+        SimplicialComplex::closure(self.star(s))
+    }
+
+    /// The link of s is the set of simplices in the closed star of s which are disjoint from s.
+    pub fn link(&self, s: &Simplex<T>) -> SimplicialComplex<T> {
+        // Every simplex of the link is t \ s for some t in the star of s.
+        SimplicialComplex::new(self.star(s).into_iter()
+            .filter(|t| t != s)
+            .map(|t| t.vertices.difference(&s.vertices).cloned().collect())
+            .collect())
+    }
+}
@@ -0,0 +1,36 @@
+use super::simplicial_complex::SimplicialComplex;
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The k-skeleton is the subcomplex of all simplices of dimension at most k.
+    pub fn skeleton(&self, k: usize) -> SimplicialComplex<T> {
+        SimplicialComplex::new(self.simplices.iter()
+            .filter(|s| s.dim() <= k)
+            .cloned()
+            .collect())
+    }
+
+    /// Whether every simplex of self is a simplex of other.
+    pub fn is_subcomplex(&self, other: &SimplicialComplex<T>) -> bool {
+        self.simplices.is_subset(&other.simplices)
+    }
+
+    /// The union of two complexes is again a complex.
+    pub fn union(&self, other: &SimplicialComplex<T>) -> SimplicialComplex<T> {
+        SimplicialComplex::new(self.simplices.union(&other.simplices)
+            .cloned()
+            .collect())
+    }
+
+    /// The intersection of two complexes is again a complex.
+    pub fn intersection(&self, other: &SimplicialComplex<T>) -> SimplicialComplex<T> {
+        SimplicialComplex::new(self.simplices.intersection(&other.simplices)
+            .cloned()
+            .collect())
+    }
+
+    /// The set difference of two complexes is not a complex in general, so we return its closure,
+    /// ie. the smallest subcomplex of self containing every simplex which is not in other.
+    pub fn difference(&self, other: &SimplicialComplex<T>) -> SimplicialComplex<T> {
+        SimplicialComplex::closure(self.simplices.difference(&other.simplices).cloned())
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::BTreeSet;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+impl<P> SimplicialComplex<P> where P: Ord + Clone {
+    /// The order complex of a finite poset, whose simplices are the non-empty chains of the poset.
+    /// `lt(a, b)` should be the strict partial order a < b on the given elements.
+    pub fn order_complex<F: Fn(&P, &P) -> bool>(elements: &BTreeSet<P>, lt: F) -> SimplicialComplex<P> {
+        // Sort the elements into a linear extension of the order. If a < b then everything below a
+        // is below b, so sorting by the number of elements below works.
+        let mut sorted: Vec<(usize, &P)> = elements.iter()
+            .map(|x| (elements.iter().filter(|y| lt(y, x)).count(), x))
+            .collect();
+        sorted.sort_by_key(|&(below, _)| below);
+        let sorted: Vec<&P> = sorted.into_iter().map(|(_, x)| x).collect();
+        // Every chain is then increasing in this order, so we extend each chain by the elements
+        // after its top element which are larger than it.
+        let mut simplices = BTreeSet::new();
+        let mut stack: Vec<(Vec<&P>, usize)> = (0..sorted.len())
+            .map(|i| (vec![sorted[i]], i))
+            .collect();
+        while let Some((chain, top)) = stack.pop() {
+            for (j, &y) in sorted.iter().enumerate().skip(top + 1) {
+                if lt(sorted[top], y) {
+                    let mut c = chain.clone();
+                    c.push(y);
+                    stack.push((c, j));
+                }
+            }
+            simplices.insert(chain.into_iter().cloned().collect());
+        }
+        SimplicialComplex::new(simplices)
+    }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The barycentric subdivision of a complex is the order complex of its simplices ordered by
+    /// inclusion. Its vertices are the simplices of the original complex, each standing for the
+    /// barycentre of that simplex.
+    pub fn barycentric_subdivision(&self) -> SimplicialComplex<Simplex<T>> {
+        SimplicialComplex::order_complex(&self.simplices, |s, t| s != t && s.is_face(t))
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+
+use nalgebra::{DMatrix, DVector};
+
+use super::chain_complex::{ChainComplex, Homology};
+use super::chain_map::ChainMap;
+use super::product::lattice_paths;
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+use super::linear_algebra::coefficients::Coefficients;
+use super::linear_algebra::integer::invariant_factors;
+
+impl ChainComplex {
+    /// The tensor product C ⊗ D, which has (C ⊗ D)_n = ⊕_{p+q=n} C_p ⊗ D_q and differential
+    /// d(c ⊗ e) = dc ⊗ e + (-1)^p c ⊗ de for c in C_p.
+    ///
+    /// The basis of (C ⊗ D)_n is ordered by p, and then lexicographically, so c_i ⊗ e_j in
+    /// C_p ⊗ D_q comes i rank(D_q) + j places after the start of the C_p ⊗ D_q block.
+    pub fn tensor(&self, other: &ChainComplex) -> ChainComplex {
+        let mut differentials = vec![DMatrix::zeros(0, self.rank(0) * other.rank(0))];
+        for n in 1..=self.dim() + other.dim() {
+            let mut m = DMatrix::zeros(offset(self, other, n - 1, n), offset(self, other, n, n + 1));
+            for p in 0..=n {
+                let q = n - p;
+                let col = offset(self, other, n, p);
+                if p > 0 {
+                    let block = self.differential(p).kronecker(&DMatrix::identity(other.rank(q), other.rank(q)));
+                    m.slice_mut((offset(self, other, n - 1, p - 1), col), block.shape()).copy_from(&block);
+                }
+                if q > 0 {
+                    let block = DMatrix::identity(self.rank(p), self.rank(p)).kronecker(&other.differential(q));
+                    let block = if p % 2 == 0 { block } else { -block };
+                    m.slice_mut((offset(self, other, n - 1, p), col), block.shape()).copy_from(&block);
+                }
+            }
+            differentials.push(m);
+        }
+        ChainComplex::new(differentials).unwrap()
+    }
+
+    /// The n-th integral homology of C ⊗ D, as predicted by the Künneth formula
+    ///
+    /// ```text
+    /// H_n(C ⊗ D) = ⊕_{p+q=n} H_p(C) ⊗ H_q(D) ⊕ ⊕_{p+q=n-1} Tor(H_p(C), H_q(D))
+    /// ```
+    ///
+    /// which holds as the chain groups are free.
+    pub fn kunneth(&self, other: &ChainComplex, n: usize) -> Homology {
+        let h = |c: &ChainComplex, k: usize| c.homology(k, Coefficients::Integers);
+        let mut result = Homology { rank: 0, torsion: vec![] };
+        for p in 0..=n {
+            result = result.direct_sum(&h(self, p).tensor(&h(other, n - p)));
+        }
+        for p in 0..n {
+            result = result.direct_sum(&h(self, p).tor(&h(other, n - 1 - p)));
+        }
+        result
+    }
+}
+
+/// The position of the C_p ⊗ D_{n-p} block in the basis of (C ⊗ D)_n, which for p > n is the
+/// rank of (C ⊗ D)_n.
+fn offset(c: &ChainComplex, d: &ChainComplex, n: usize, p: usize) -> usize {
+    (0..p.min(n + 1))
+        .map(|i| c.rank(i) * d.rank(n - i))
+        .sum()
+}
+
+impl Homology {
+    /// The group Z^rank ⊕ Z/t_1 ⊕ ... ⊕ Z/t_m for any orders t_i, written in invariant factor
+    /// form.
+    fn from_cyclic(rank: usize, orders: Vec<i64>) -> Homology {
+        let m = DMatrix::from_diagonal(&DVector::from_vec(orders));
+        Homology {
+            rank,
+            torsion: invariant_factors(&m).into_iter().filter(|&t| t != 1).collect(),
+        }
+    }
+
+    pub fn direct_sum(&self, other: &Homology) -> Homology {
+        let orders = self.torsion.iter().chain(&other.torsion).copied().collect();
+        Homology::from_cyclic(self.rank + other.rank, orders)
+    }
+
+    /// The tensor product over the integers, using Z ⊗ A = A and Z/s ⊗ Z/t = Z/gcd(s, t).
+    pub fn tensor(&self, other: &Homology) -> Homology {
+        let mut orders = vec![];
+        for _ in 0..self.rank {
+            orders.extend_from_slice(&other.torsion);
+        }
+        for _ in 0..other.rank {
+            orders.extend_from_slice(&self.torsion);
+        }
+        orders.extend(self.torsion.iter()
+            .flat_map(|&s| other.torsion.iter().map(move |&t| gcd(s, t))));
+        Homology::from_cyclic(self.rank * other.rank, orders)
+    }
+
+    /// Tor over the integers, using Tor(Z, A) = 0 and Tor(Z/s, Z/t) = Z/gcd(s, t).
+    pub fn tor(&self, other: &Homology) -> Homology {
+        let orders = self.torsion.iter()
+            .flat_map(|&s| other.torsion.iter().map(move |&t| gcd(s, t)))
+            .collect();
+        Homology::from_cyclic(0, orders)
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+impl<T> SimplicialComplex<T> where T: Ord + Clone {
+    /// The Eilenberg–Zilber shuffle map C(K) ⊗ C(L) -> C(K × L), where the product is
+    /// triangulated as in `SimplicialComplex::product`. It sends σ ⊗ τ, for a p-simplex σ and a
+    /// q-simplex τ, to the sum of the staircase simplices of σ × τ, each with the sign of the
+    /// corresponding (p, q)-shuffle. This is a chain homotopy equivalence, so it induces
+    /// isomorphisms on homology.
+    pub fn eilenberg_zilber<U: Ord + Clone>(&self, other: &SimplicialComplex<U>) -> ChainMap {
+        let (c, d) = (ChainComplex::from(self), ChainComplex::from(other));
+        let product = self.product(other);
+        let source = c.tensor(&d);
+        let target = ChainComplex::from(&product);
+        let maps = (0..=source.dim())
+            .map(|n| {
+                let index: BTreeMap<Simplex<(T, U)>, usize> = product.dim_simplices(n).into_iter()
+                    .enumerate()
+                    .map(|(i, s)| (s, i))
+                    .collect();
+                let mut m = DMatrix::zeros(target.rank(n), source.rank(n));
+                for p in 0..=n {
+                    let ls = other.dim_simplices(n - p);
+                    let paths = lattice_paths(p, n - p);
+                    for (i, s) in self.dim_simplices(p).iter().enumerate() {
+                        for (j, t) in ls.iter().enumerate() {
+                            let col = offset(&c, &d, n, p) + i * ls.len() + j;
+                            let a: Vec<&T> = s.vertices.iter().collect();
+                            let b: Vec<&U> = t.vertices.iter().collect();
+                            for path in &paths {
+                                let simplex: Simplex<(T, U)> = path.iter()
+                                    .map(|&(x, y)| (a[x].clone(), b[y].clone()))
+                                    .collect();
+                                m[(index[&simplex], col)] += shuffle_sign(path);
+                            }
+                        }
+                    }
+                }
+                m
+            })
+            .collect();
+        ChainMap::new(source, target, maps).unwrap()
+    }
+}
+
+/// The sign of the shuffle given by a lattice path, where the steps in the first coordinate come
+/// before the steps in the second. Each pair of a step in the second coordinate followed by one
+/// in the first is an inversion.
+fn shuffle_sign(path: &[(usize, usize)]) -> i64 {
+    let inversions: usize = path.windows(2)
+        .filter(|w| w[1].0 > w[0].0)
+        .map(|w| w[0].1)
+        .sum();
+    if inversions.is_multiple_of(2) { 1 } else { -1 }
+}
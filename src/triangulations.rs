@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use super::simplex::Simplex;
+use super::simplicial_complex::SimplicialComplex;
+
+fn from_facets(facets: &[[usize; 3]]) -> SimplicialComplex<usize> {
+    SimplicialComplex::closure(facets.iter()
+        .map(|f| f.iter().copied().collect()))
+}
+
+impl SimplicialComplex<usize> {
+    /// The boundary of the (n+1)-simplex, which is a triangulation of the n-sphere with n + 2
+    /// vertices.
+    ///
+    /// Integral homology: H_0 = Z and H_n = Z (H_0 = Z^2 when n = 0), all others vanish.
+    pub fn sphere(n: usize) -> SimplicialComplex<usize> {
+        SimplicialComplex::hollow(n + 1)
+    }
+
+    /// The 7 vertex triangulation of the torus (Möbius, Császár).
+    ///
+    /// Integral homology: H_0 = Z, H_1 = Z^2, H_2 = Z.
+    pub fn torus() -> SimplicialComplex<usize> {
+        let facets: Vec<[usize; 3]> = (0..7)
+            .flat_map(|i| vec![[i, (i + 1) % 7, (i + 3) % 7], [i, (i + 2) % 7, (i + 3) % 7]])
+            .collect();
+        from_facets(&facets)
+    }
+
+    /// An 8 vertex triangulation of the Klein bottle, which is the minimal number of vertices.
+    ///
+    /// Integral homology: H_0 = Z, H_1 = Z ⊕ Z/2, H_2 = 0.
+    pub fn klein_bottle() -> SimplicialComplex<usize> {
+        from_facets(&[
+            [0, 1, 3], [0, 1, 6], [0, 2, 4], [0, 2, 5], [0, 3, 5], [0, 4, 6], [1, 2, 4], [1, 2, 7],
+            [1, 3, 4], [1, 5, 6], [1, 5, 7], [2, 5, 6], [2, 6, 7], [3, 4, 5], [4, 5, 7], [4, 6, 7],
+        ])
+    }
+
+    /// The 6 vertex triangulation of the real projective plane, given by identifying opposite
+    /// faces of the icosahedron.
+    ///
+    /// Integral homology: H_0 = Z, H_1 = Z/2, H_2 = 0.
+    pub fn projective_plane() -> SimplicialComplex<usize> {
+        from_facets(&[
+            [0, 1, 2], [0, 2, 3], [0, 3, 4], [0, 4, 5], [0, 1, 5],
+            [1, 2, 4], [2, 3, 5], [1, 3, 4], [2, 4, 5], [1, 3, 5],
+        ])
+    }
+
+    /// The 5 vertex triangulation of the Möbius strip. Its boundary is the cycle of edges
+    /// [i, i + 2] (mod 5).
+    ///
+    /// Integral homology: H_0 = Z, H_1 = Z, H_2 = 0.
+    pub fn mobius_strip() -> SimplicialComplex<usize> {
+        let facets: Vec<[usize; 3]> = (0..5)
+            .map(|i| [i, (i + 1) % 5, (i + 2) % 5])
+            .collect();
+        from_facets(&facets)
+    }
+
+    /// An 8 vertex triangulation of the dunce hat, ie. a triangle with its edges identified by the
+    /// word a a a^-1. The three edges [0, 1], [1, 2] and [0, 2] are the image of the edge a. The
+    /// dunce hat is contractible, but every edge is a face of at least two triangles, so it is not
+    /// collapsible.
+    ///
+    /// Integral homology: H_0 = Z, all others vanish.
+    pub fn dunce_hat() -> SimplicialComplex<usize> {
+        from_facets(&[
+            [0, 1, 3], [0, 1, 4], [0, 1, 7], [0, 2, 4], [0, 2, 5], [0, 2, 6], [0, 3, 7], [0, 5, 6],
+            [1, 2, 3], [1, 2, 5], [1, 2, 6], [1, 4, 5], [1, 6, 7], [2, 3, 4], [3, 4, 5], [3, 5, 7],
+            [5, 6, 7],
+        ])
+    }
+
+    /// A triangulation of the lens space L(p, 1), for p >= 1.
+    ///
+    /// The 3-sphere is the join of two circles, which we triangulate as the join of two n-gons.
+    /// L(p, 1) is the quotient by rotating both circles by 2π/p, ie. by n/p steps. This quotient is
+    /// not a simplicial complex, but as no simplex contains two vertices in the same orbit, the
+    /// quotient of the barycentric subdivision is, so that is what we return. The subdivision makes
+    /// it far from minimal, eg. L(2, 1) has f-vector (40, 232, 384, 192).
+    ///
+    /// Integral homology: H_0 = Z, H_1 = Z/p, H_2 = 0, H_3 = Z.
+    pub fn lens_space(p: usize) -> SimplicialComplex<usize> {
+        if p == 0 {
+            panic!("Cannot make the lens space L(0, 1)");
+        }
+        // Each orbit has to skip at least one vertex of the n-gon, and we need n >= 3.
+        let m = if p == 1 { 3 } else { 2 };
+        let n = m * p;
+        // The vertices 0..n are the first circle, and n..2n the second.
+        let sphere = SimplicialComplex::closure((0..n)
+            .flat_map(|i| (0..n)
+                .map(move |j| vec![i, (i + 1) % n, n + j, n + (j + 1) % n].into())));
+        let rotate = |s: &Simplex<usize>, k: usize| -> Simplex<usize> {
+            s.vertices.iter()
+                .map(|&v| (v / n) * n + (v % n + k * m) % n)
+                .collect()
+        };
+        // Send each simplex to the least element of its orbit.
+        let quotient = sphere.barycentric_subdivision()
+            .map_vertices(|s| (0..p)
+                .map(|k| rotate(&s, k))
+                .min()
+                .unwrap());
+        let labels: BTreeMap<_, _> = quotient.vertices.iter()
+            .enumerate()
+            .map(|(i, s)| (s.clone(), i))
+            .collect();
+        quotient.map_vertices(|s| labels[&s])
+    }
+}
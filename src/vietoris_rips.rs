@@ -7,14 +7,14 @@ use super::simplex::Simplex;
 use super::simplicial_complex::SimplicialComplex;
 use super::filtration::Filtration;
 
-fn vr_simplex_step(adj: &DMatrix<bool>, simplices: &BTreeSet<Simplex<usize>>, dim: bool) -> BTreeSet<Simplex<usize>> {
+fn vr_simplex_step(adj: &DMatrix<bool>, simplices: &BTreeSet<Simplex<usize>>, dim: bool, max_dim: usize) -> BTreeSet<Simplex<usize>> {
     let mut new_simplices = BTreeSet::new();
     let d = simplices.iter()
                            .map(|s| s.dim())
                            .max()
-                           .unwrap();
+                           .unwrap_or(0);
     for simplex in simplices {
-        if dim && simplex.dim() < d {
+        if (dim && simplex.dim() < d) || simplex.dim() >= max_dim {
             continue;
         }
         let mut with_new_vert = (0..adj.nrows())
@@ -28,10 +28,10 @@ fn vr_simplex_step(adj: &DMatrix<bool>, simplices: &BTreeSet<Simplex<usize>>, di
     simplices.union(&new_simplices).cloned().collect()
 }
 
-fn vr_simplices(adj: &DMatrix<bool>, simplices: BTreeSet<Simplex<usize>>, dim: bool) -> BTreeSet<Simplex<usize>> {
+fn vr_simplices(adj: &DMatrix<bool>, simplices: BTreeSet<Simplex<usize>>, dim: bool, max_dim: usize) -> BTreeSet<Simplex<usize>> {
     let mut simplices = simplices;
     loop {
-        let new_simplices = vr_simplex_step(&adj, &simplices, dim);
+        let new_simplices = vr_simplex_step(adj, &simplices, dim, max_dim);
         if new_simplices.len() == simplices.len() {
             break;
         }
@@ -40,18 +40,25 @@ fn vr_simplices(adj: &DMatrix<bool>, simplices: BTreeSet<Simplex<usize>>, dim: b
     simplices
 }
 
-fn vr_simplicial_complex(adj: &DMatrix<bool>) -> SimplicialComplex<usize> {
+pub(crate) fn vr_simplicial_complex(adj: &DMatrix<bool>, max_dim: usize) -> SimplicialComplex<usize> {
     // Insert all 0-simplices
     let simplices = (0..adj.nrows())
         .map(|i| Simplex::from_iter([i]))
                        .collect();
-    SimplicialComplex::new(vr_simplices(adj, simplices, true))
+    SimplicialComplex::new(vr_simplices(adj, simplices, true, max_dim))
 }
 
 /// Given a distance matrix, and epsilon, return the Vietoris-Rips complex.
 pub fn vietoris_rips_simplicial_complex(dist: &DMatrix<f64>, epsilon: f64) -> SimplicialComplex<usize> {
     let adj = dist.map(|x| x <= epsilon);
-    vr_simplicial_complex(&adj)
+    vr_simplicial_complex(&adj, usize::MAX)
+}
+
+/// Given a distance matrix, epsilon and max_dim, return the max_dim-skeleton of the
+/// Vietoris-Rips complex. Simplices of higher dimension are never built.
+pub fn vietoris_rips_skeleton(dist: &DMatrix<f64>, epsilon: f64, max_dim: usize) -> SimplicialComplex<usize> {
+    let adj = dist.map(|x| x <= epsilon);
+    vr_simplicial_complex(&adj, max_dim)
 }
 
 /// Given a distance matrix, epsilon, and the VR complex for some delta < epsilon, return the
@@ -62,7 +69,7 @@ pub fn vietoris_rips_simplicial_complex_step(dist: &DMatrix<f64>, epsilon: f64,
     let adj = dist.map(|x| x <= epsilon);
     // When we increase epsilon, we can't only add max dimension simplices, we also need to add
     // lower dimension simplices as well.
-    SimplicialComplex::new(vr_simplices(&adj, k.simplices, false))
+    SimplicialComplex::new(vr_simplices(&adj, k.simplices, false, usize::MAX))
 }
 
 pub fn vietoris_rips_filtration(dist: &DMatrix<f64>, epsilons: Vec<f64>) -> Filtration<usize> {
@@ -141,3 +141,27 @@ fn betti_shape() {
     assert_eq!(k.betti(2), 0);
     assert_eq!(k.betti(3), 0);
 }
+
+#[test]
+fn augmented_boundary() {
+    let k = SimplicialComplex::solid(2);
+    assert_eq!(k.augmented_boundary::<i32>(0), dmatrix![1, 1, 1]);
+    assert_eq!(k.augmented_boundary::<i32>(1), k.boundary::<i32>(1));
+    // The augmentation composed with the first boundary map is zero
+    assert_eq!(k.augmented_boundary::<i32>(0) * k.augmented_boundary::<i32>(1), DMatrix::zeros(1, 3));
+}
+
+#[test]
+fn reduced_betti() {
+    let k = SimplicialComplex::solid(2);
+    assert_eq!(k.reduced_betti(0), 0);
+    assert_eq!(k.reduced_betti(1), 0);
+    let k = SimplicialComplex::hollow(1);
+    assert_eq!(k.reduced_betti(0), 1);
+    let k = SimplicialComplex::hollow(3);
+    assert_eq!(k.reduced_betti(0), 0);
+    assert_eq!(k.reduced_betti(1), 0);
+    assert_eq!(k.reduced_betti(2), 1);
+    let k: SimplicialComplex<usize> = SimplicialComplex::from(vec![]);
+    assert_eq!(k.reduced_betti(0), 0);
+}
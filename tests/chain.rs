@@ -0,0 +1,63 @@
+use tda::chain::Chain;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+use tda::linear_algebra::coefficients::Coefficients;
+
+#[test]
+fn arithmetic() {
+    let a: Chain<usize, i64> = vec![(Simplex::from(vec![0, 1]), 2), (Simplex::from(vec![1, 2]), -1)].into_iter().collect();
+    let b = Chain::simplex(Simplex::from(vec![1, 2]));
+    assert_eq!((a.clone() + b.clone()).coefficient(&Simplex::from(vec![1, 2])), 0);
+    assert_eq!((a.clone() + b.clone()).support().len(), 1);
+    assert_eq!((a.clone() - b.clone()).coefficient(&Simplex::from(vec![1, 2])), -2);
+    assert_eq!(-(a.clone() * 3), Chain::term(Simplex::from(vec![0, 1]), -6) + Chain::term(Simplex::from(vec![1, 2]), 3));
+    assert!((a.clone() - a).is_zero());
+    assert!(Chain::<usize, i64>::term(Simplex::from(vec![0]), 0).is_zero());
+}
+
+#[test]
+fn boundary_of_chain() {
+    // d(2[0, 1] - [1, 2]) = 2[1] - 2[0] - [2] + [1]
+    let a: Chain<usize, i64> = vec![(Simplex::from(vec![0, 1]), 2), (Simplex::from(vec![1, 2]), -1)].into_iter().collect();
+    let expected: Chain<usize, i64> = vec![(Simplex::from(vec![0]), -2), (Simplex::from(vec![1]), 3), (Simplex::from(vec![2]), -1)].into_iter().collect();
+    assert_eq!(a.boundary(), expected);
+    assert!(!a.is_cycle());
+    assert!(a.boundary().is_cycle());
+}
+
+#[test]
+fn boundary_of_boundary_is_zero() {
+    let k = SimplicialComplex::<usize>::solid(4);
+    let c: Chain<usize, i64> = k.dim_simplices(3).into_iter()
+        .enumerate()
+        .map(|(i, t)| (t, i as i64 + 1))
+        .collect();
+    assert!(!c.boundary().is_zero());
+    assert!(c.boundary().boundary().is_zero());
+}
+
+#[test]
+fn cycles_and_boundaries() {
+    let triangle = SimplicialComplex::<usize>::hollow(2);
+    let disk = SimplicialComplex::<usize>::solid(2);
+    let cycle: Chain<usize, i64> = Chain::simplex(Simplex::from(vec![0, 1, 2])).boundary();
+    assert!(cycle.is_cycle());
+    assert!(!cycle.is_boundary(&triangle, Coefficients::Integers));
+    assert!(cycle.is_boundary(&disk, Coefficients::Integers));
+    assert!((cycle.clone() * 3).is_boundary(&disk, Coefficients::Integers));
+    assert!(!cycle.is_boundary(&SimplicialComplex::<usize>::solid(1), Coefficients::Integers));
+}
+
+#[test]
+fn torsion_boundaries() {
+    // [0, 1, 3] is not a face of the projective plane, and its boundary is a loop generating
+    // H_1 = Z/2
+    let k = SimplicialComplex::projective_plane();
+    let cycle: Chain<usize, i64> = Chain::simplex(Simplex::from(vec![0, 1, 3])).boundary();
+    assert!(!k.simplices.contains(&Simplex::from(vec![0, 1, 3])));
+    assert!(!cycle.is_boundary(&k, Coefficients::Integers));
+    assert!(!cycle.is_boundary(&k, Coefficients::prime(2).unwrap()));
+    assert!(cycle.is_boundary(&k, Coefficients::Rationals));
+    assert!(cycle.is_boundary(&k, Coefficients::prime(3).unwrap()));
+    assert!((cycle * 2).is_boundary(&k, Coefficients::Integers));
+}
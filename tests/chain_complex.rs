@@ -0,0 +1,73 @@
+use tda::chain_complex::{ChainComplex, ChainComplexError, Homology};
+use tda::simplicial_complex::SimplicialComplex;
+use tda::linear_algebra::coefficients::Coefficients;
+use tda::linear_algebra::sparse::SparseMatrix;
+use nalgebra::{DMatrix, dmatrix};
+
+mod common;
+
+fn free(rank: usize) -> Homology {
+    Homology { rank, torsion: vec![] }
+}
+
+#[test]
+fn cellular_circle() {
+    // One 0-cell and one 1-cell, with zero differential
+    let c = ChainComplex::new(vec![DMatrix::zeros(0, 1), DMatrix::zeros(1, 1)]).unwrap();
+    assert_eq!(c.dim(), 1);
+    assert_eq!(c.homology(0, Coefficients::Integers), free(1));
+    assert_eq!(c.homology(1, Coefficients::Integers), free(1));
+    assert_eq!(c.homology(2, Coefficients::Integers), free(0));
+}
+
+#[test]
+fn cellular_projective_plane() {
+    let c = common::cellular_projective_plane();
+    assert_eq!(c.homology(0, Coefficients::Integers), free(1));
+    assert_eq!(c.homology(1, Coefficients::Integers), Homology { rank: 0, torsion: vec![2] });
+    assert_eq!(c.homology(2, Coefficients::Integers), free(0));
+    assert_eq!(c.betti(1, Coefficients::prime(2).unwrap()), 1);
+    assert_eq!(c.betti(2, Coefficients::prime(2).unwrap()), 1);
+    assert_eq!(c.betti(1, Coefficients::prime(3).unwrap()), 0);
+}
+
+#[test]
+fn invalid_complexes() {
+    assert_eq!(ChainComplex::new(vec![DMatrix::zeros(1, 1)]), Err(ChainComplexError::NonZeroTarget));
+    assert_eq!(ChainComplex::new(vec![DMatrix::zeros(0, 2), dmatrix![1; 1; 1]]), Err(ChainComplexError::ShapeMismatch(1)));
+    assert_eq!(ChainComplex::new(vec![DMatrix::zeros(0, 1), dmatrix![1], dmatrix![1]]), Err(ChainComplexError::NotAComplex(2)));
+    assert_eq!(ChainComplex::new(vec![DMatrix::zeros(0, 1), dmatrix![i64::MAX], dmatrix![2]]), Err(ChainComplexError::Overflow(2)));
+}
+
+#[test]
+fn simplicial_chain_complex() {
+    let k = SimplicialComplex::torus();
+    let c = ChainComplex::from(&k);
+    assert_eq!(c.rank(1), 21);
+    assert_eq!(c.differential(2), k.boundary::<i64>(2));
+    assert_eq!(c.differentials()[2], SparseMatrix::from(k.boundary::<i64>(2)));
+    for i in 0..=3 {
+        assert_eq!(c.betti(i, Coefficients::Rationals), k.betti(i));
+    }
+}
+
+#[test]
+fn integral_homology_of_surfaces() {
+    let k = SimplicialComplex::klein_bottle();
+    assert_eq!(k.homology(0, Coefficients::Integers), free(1));
+    assert_eq!(k.homology(1, Coefficients::Integers), Homology { rank: 1, torsion: vec![2] });
+    assert_eq!(k.homology(2, Coefficients::Integers), free(0));
+    assert_eq!(k.homology(2, Coefficients::prime(2).unwrap()), free(1));
+    let k = SimplicialComplex::projective_plane();
+    assert_eq!(k.homology(1, Coefficients::Integers), Homology { rank: 0, torsion: vec![2] });
+    let k = SimplicialComplex::torus();
+    assert_eq!(k.homology(1, Coefficients::Integers), free(2));
+}
+
+#[test]
+fn integral_homology_of_lens_space() {
+    let k = SimplicialComplex::lens_space(3);
+    assert_eq!(k.homology(1, Coefficients::Integers), Homology { rank: 0, torsion: vec![3] });
+    assert_eq!(k.homology(2, Coefficients::Integers), free(0));
+    assert_eq!(k.homology(3, Coefficients::Integers), free(1));
+}
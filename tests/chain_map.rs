@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+
+use tda::chain_complex::ChainComplex;
+use tda::chain_map::{ChainMap, ChainMapError};
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplicial_map::SimplicialMap;
+use tda::linear_algebra::coefficients::Coefficients;
+use nalgebra::{DMatrix, dmatrix};
+
+fn circle() -> ChainComplex {
+    ChainComplex::new(vec![DMatrix::zeros(0, 1), DMatrix::zeros(1, 1)]).unwrap()
+}
+
+#[test]
+fn degree_maps() {
+    // The degree 2 map on the cellular circle
+    let f = ChainMap::new(circle(), circle(), vec![dmatrix![1], dmatrix![2]]).unwrap();
+    let h1 = f.induced_map(1, Coefficients::Rationals).unwrap();
+    assert_eq!((h1.source, h1.target, h1.rank), (1, 1, 1));
+    assert_eq!(f.induced_map(1, Coefficients::prime(2).unwrap()).unwrap().rank, 0);
+    assert_eq!(f.induced_map(1, Coefficients::Integers), None);
+    // The cone of a degree 2 map is a cellular projective plane
+    let cone = f.mapping_cone();
+    assert_eq!(cone.homology(1, Coefficients::Integers).torsion, vec![2]);
+    assert_eq!(cone.betti(2, Coefficients::Integers), 0);
+}
+
+#[test]
+fn invalid_chain_maps() {
+    assert_eq!(ChainMap::new(circle(), circle(), vec![dmatrix![1, 1]]), Err(ChainMapError::ShapeMismatch(0)));
+    let disk = ChainComplex::new(vec![DMatrix::zeros(0, 1), dmatrix![0], dmatrix![1]]).unwrap();
+    // Including the circle as the boundary of the disk is fine, but collapsing the disk onto the
+    // circle is not
+    assert!(ChainMap::new(circle(), disk.clone(), vec![dmatrix![1], dmatrix![1]]).is_ok());
+    assert_eq!(ChainMap::new(disk, circle(), vec![dmatrix![1], dmatrix![1], DMatrix::zeros(0, 1)]), Err(ChainMapError::NotAChainMap(2)));
+}
+
+#[test]
+fn cone_of_identity_is_acyclic() {
+    let k = SimplicialComplex::torus();
+    let id = SimplicialMap::from_fn(k.clone(), k, |&v| v).unwrap();
+    let cone = ChainMap::from(&id).mapping_cone();
+    for i in 0..=cone.dim() {
+        assert_eq!(cone.betti(i, Coefficients::Rationals), 0);
+        assert!(cone.homology(i, Coefficients::Integers).torsion.is_empty());
+    }
+}
+
+#[test]
+fn cone_of_inclusion_is_relative_homology() {
+    let k = SimplicialComplex::<usize>::solid(3);
+    let l = k.skeleton(1);
+    let f = SimplicialMap::from_fn(l.clone(), k.clone(), |&v| v).unwrap();
+    let cone = ChainMap::from(&f).mapping_cone();
+    for i in 0..=3 {
+        assert_eq!(cone.betti(i, Coefficients::Rationals), k.relative_betti(i, &l));
+    }
+}
+
+#[test]
+fn shift() {
+    let c = circle().shift();
+    assert_eq!(c.dim(), 2);
+    assert_eq!(c.betti(0, Coefficients::Rationals), 0);
+    assert_eq!(c.betti(1, Coefficients::Rationals), 1);
+    assert_eq!(c.betti(2, Coefficients::Rationals), 1);
+}
+
+#[test]
+fn long_exact_sequence_of_disk_and_boundary() {
+    let k = SimplicialComplex::<usize>::solid(2);
+    let l = k.skeleton(1);
+    let les = k.long_exact_sequence(&l, Coefficients::Rationals).unwrap();
+    assert!(les.is_exact());
+    // H_k(L), H_k(K), H_k(K, L) for k = 3, 2, 1, 0
+    assert_eq!(les.groups, vec![0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0]);
+    // The connecting map H_2(K, L) -> H_1(L) is an isomorphism
+    assert_eq!(les.ranks[5], 1);
+}
+
+#[test]
+fn long_exact_sequences_are_exact() {
+    let k = SimplicialComplex::projective_plane();
+    let triangles: BTreeSet<Simplex<usize>> = k.dim_simplices(2).into_iter().take(3).collect();
+    let l = SimplicialComplex::closure(triangles);
+    for &coefficients in &[Coefficients::Rationals, Coefficients::prime(2).unwrap(), Coefficients::prime(3).unwrap()] {
+        assert!(k.long_exact_sequence(&l, coefficients).unwrap().is_exact());
+        assert!(k.long_exact_sequence(&k.skeleton(1), coefficients).unwrap().is_exact());
+    }
+    assert_eq!(k.long_exact_sequence(&l, Coefficients::Integers), None);
+}
@@ -0,0 +1,95 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use tda::random::random_vietoris_rips;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+
+/// The Betti numbers without trailing zeros, as collapsing can lower the dimension.
+fn betti(k: &SimplicialComplex<usize>) -> Vec<usize> {
+    let mut b = k.betti_numbers();
+    while b.len() > 1 && b.last() == Some(&0) {
+        b.pop();
+    }
+    b
+}
+
+#[test]
+fn free_faces() {
+    let k = SimplicialComplex::<usize>::solid(2);
+    assert_eq!(k.free_faces(), vec![
+        (Simplex::from(vec![0, 1]), Simplex::from(vec![0, 1, 2])),
+        (Simplex::from(vec![0, 2]), Simplex::from(vec![0, 1, 2])),
+        (Simplex::from(vec![1, 2]), Simplex::from(vec![0, 1, 2])),
+    ]);
+    assert!(SimplicialComplex::torus().free_faces().is_empty());
+    assert!(SimplicialComplex::dunce_hat().free_faces().is_empty());
+}
+
+#[test]
+fn elementary_collapses() {
+    let k = SimplicialComplex::<usize>::solid(2);
+    let l = k.elementary_collapse(&Simplex::from(vec![0, 1])).unwrap();
+    assert_eq!(l, SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![0, 2], vec![1, 2]]));
+    assert_eq!(k.elementary_collapse(&Simplex::from(vec![0])), None);
+    assert_eq!(k.elementary_collapse(&Simplex::from(vec![0, 1, 2])), None);
+    // A leaf of a graph is free
+    assert_eq!(l.elementary_collapse(&Simplex::from(vec![0])), Some(SimplicialComplex::from(vec![vec![1], vec![2], vec![1, 2]])));
+}
+
+#[test]
+fn collapsible_complexes() {
+    for n in 0..5 {
+        let k = SimplicialComplex::<usize>::solid(n).collapse();
+        assert_eq!(k.simplices.len(), 1);
+        assert!(k.is_valid());
+    }
+    assert!(SimplicialComplex::<usize>::solid(3).skeleton(2).difference(&SimplicialComplex::from(vec![vec![0, 1, 2]])).is_collapsible());
+}
+
+#[test]
+fn collapsing_preserves_homology() {
+    let mobius = SimplicialComplex::mobius_strip();
+    let k = mobius.collapse();
+    assert_eq!(k.dim(), 1);
+    assert_eq!(betti(&k), vec![1, 1]);
+    assert!(k.is_subcomplex(&mobius));
+    let dunce = SimplicialComplex::dunce_hat();
+    assert_eq!(dunce.collapse(), dunce);
+    assert!(!dunce.is_collapsible());
+    assert_eq!(SimplicialComplex::torus().collapse(), SimplicialComplex::torus());
+}
+
+#[test]
+fn strong_collapses() {
+    let k = SimplicialComplex::<usize>::solid(3);
+    assert_eq!(k.dominated_vertices().len(), 4);
+    assert_eq!(k.strong_collapse().simplices.len(), 1);
+    let cone = SimplicialComplex::torus().cone();
+    assert_eq!(cone.strong_collapse().simplices.len(), 1);
+    // Spheres and circles have no dominated vertices
+    for k in &[SimplicialComplex::<usize>::sphere(2), SimplicialComplex::<usize>::hollow(2), SimplicialComplex::torus()] {
+        assert!(k.dominated_vertices().is_empty());
+        assert_eq!(&k.strong_collapse(), k);
+    }
+    // A path strongly collapses to a point
+    let path = SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![0, 1], vec![1, 2]]);
+    assert_eq!(path.dominated_vertices(), vec![(0, 1), (2, 1)]);
+    assert_eq!(path.strong_collapse().simplices.len(), 1);
+}
+
+#[test]
+fn vietoris_rips_reduction() {
+    let mut rng = StdRng::seed_from_u64(3);
+    for _ in 0..5 {
+        let k = random_vietoris_rips(15, 2, 0.4, &mut rng);
+        let strong = k.strong_collapse();
+        let reduced = strong.collapse();
+        assert!(reduced.simplices.len() <= strong.simplices.len());
+        assert!(strong.simplices.len() <= k.simplices.len());
+        assert!(reduced.is_valid());
+        assert_eq!(betti(&strong), betti(&k));
+        assert_eq!(betti(&reduced), betti(&k));
+        assert_eq!(betti(&k.collapse()), betti(&k));
+    }
+}
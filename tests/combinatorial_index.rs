@@ -0,0 +1,98 @@
+use tda::combinatorial_index::CombinatorialIndex;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn index_round_trip() {
+    let idx = CombinatorialIndex::<u64>::new(6, 3).unwrap();
+    for k in 0..=3 {
+        let count = idx.count(k);
+        for i in 0..count {
+            let s = idx.simplex(i, k);
+            assert_eq!(s.dim(), k);
+            assert_eq!(idx.index(&s), i);
+        }
+    }
+    assert_eq!(idx.count(2), 20);
+    assert_eq!(idx.index(&vec![0, 1, 2].into()), 0);
+    assert_eq!(idx.index(&vec![0, 1, 3].into()), 1);
+    assert_eq!(idx.index(&vec![3, 4, 5].into()), 19);
+}
+
+#[test]
+fn boundary_matches_simplex() {
+    let idx = CombinatorialIndex::<u128>::new(5, 3).unwrap();
+    for k in 1..=3 {
+        for i in 0..idx.count(k) {
+            let s = idx.simplex(i, k);
+            for (f, c) in idx.boundary::<i32>(i, k) {
+                let t = idx.simplex(f, k - 1);
+                assert!(t.is_face(&s));
+                assert_eq!(c, s.boundary_coeff::<i32>(&t));
+            }
+        }
+    }
+}
+
+#[test]
+fn coboundary_matches_boundary() {
+    let idx = CombinatorialIndex::<u64>::new(5, 3).unwrap();
+    for k in 0..3 {
+        for i in 0..idx.count(k) {
+            let cofaces = idx.coboundary::<i32>(i, k);
+            assert_eq!(cofaces.len(), 5 - (k + 1));
+            for (j, c) in cofaces {
+                assert!(idx.boundary::<i32>(j, k + 1).contains(&(i, c)));
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_matrix_of_solid_simplex() {
+    // Generating the columns from the indices gives the same boundary maps, as colexicographic
+    // order agrees with the order of the BTreeSet for the vertices of a 2-simplex.
+    let idx = CombinatorialIndex::<u64>::new(3, 2).unwrap();
+    let k = SimplicialComplex::solid(2);
+    let d2 = k.boundary::<i32>(2);
+    let s: Simplex<usize> = vec![0, 1, 2].into();
+    let mut col = idx.boundary::<i32>(idx.index(&s), 2);
+    col.sort();
+    assert_eq!(col, vec![(0, d2[(0, 0)]), (1, d2[(1, 0)]), (2, d2[(2, 0)])]);
+}
+
+#[test]
+fn index_type_too_small() {
+    // C(1000, 7) fits in a u64 but C(1000, 8) does not.
+    assert!(CombinatorialIndex::<u64>::new(1000, 6).is_some());
+    assert!(CombinatorialIndex::<u64>::new(1000, 7).is_none());
+    assert!(CombinatorialIndex::<u8>::new(20, 3).is_none());
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_range() {
+    let idx = CombinatorialIndex::<u64>::new(4, 2).unwrap();
+    idx.simplex(idx.count(1), 1);
+}
+
+#[test]
+#[should_panic(expected = "vertex out of range")]
+fn vertex_out_of_range() {
+    let idx = CombinatorialIndex::<u64>::new(4, 2).unwrap();
+    idx.index(&vec![1, 9].into());
+}
+
+#[test]
+#[should_panic(expected = "larger than the maximum dimension")]
+fn simplex_too_large() {
+    let idx = CombinatorialIndex::<u64>::new(4, 1).unwrap();
+    idx.index(&vec![0, 1, 2].into());
+}
+
+#[test]
+#[should_panic(expected = "larger than the maximum dimension")]
+fn cofaces_too_large() {
+    let idx = CombinatorialIndex::<u64>::new(4, 2).unwrap();
+    idx.coboundary::<i32>(0, 2);
+}
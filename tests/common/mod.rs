@@ -0,0 +1,8 @@
+use tda::chain_complex::ChainComplex;
+use nalgebra::{DMatrix, dmatrix};
+
+/// The cellular chain complex of the projective plane, with one cell in each dimension and
+/// d_2 = 2.
+pub fn cellular_projective_plane() -> ChainComplex {
+    ChainComplex::new(vec![DMatrix::zeros(0, 1), dmatrix![0], dmatrix![2]]).unwrap()
+}
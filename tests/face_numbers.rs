@@ -0,0 +1,43 @@
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn f_vectors() {
+    assert_eq!(SimplicialComplex::torus().f_vector(), vec![7, 21, 14]);
+    assert_eq!(SimplicialComplex::<usize>::solid(3).f_vector(), vec![4, 6, 4, 1]);
+    assert!(SimplicialComplex::<usize>::new(Default::default()).f_vector().is_empty());
+}
+
+#[test]
+fn h_vectors_of_spheres() {
+    for n in 0..5 {
+        let k = SimplicialComplex::<usize>::sphere(n);
+        assert_eq!(k.h_vector(), vec![1; n + 2]);
+        assert!(k.satisfies_dehn_sommerville());
+    }
+    // The octahedron
+    let s0 = SimplicialComplex::<usize>::sphere(0);
+    let octahedron = s0.join(&s0).join(&s0);
+    assert_eq!(octahedron.f_vector(), vec![6, 12, 8]);
+    assert_eq!(octahedron.h_vector(), vec![1, 3, 3, 1]);
+    assert_eq!(octahedron.g_vector(), vec![1, 2]);
+    assert!(octahedron.satisfies_dehn_sommerville());
+}
+
+#[test]
+fn h_vector_of_a_simplex() {
+    // A solid simplex is a cone, so its h-vector is (1, 0, ..., 0)
+    assert_eq!(SimplicialComplex::<usize>::solid(3).h_vector(), vec![1, 0, 0, 0, 0]);
+}
+
+#[test]
+fn dehn_sommerville_for_surfaces() {
+    let torus = SimplicialComplex::torus();
+    assert_eq!(torus.h_vector(), vec![1, 4, 10, -1]);
+    assert!(torus.satisfies_dehn_sommerville());
+    assert!(SimplicialComplex::projective_plane().satisfies_dehn_sommerville());
+    assert!(SimplicialComplex::klein_bottle().satisfies_dehn_sommerville());
+    // Surfaces with boundary, and non-manifolds, do not satisfy them
+    assert!(!SimplicialComplex::mobius_strip().satisfies_dehn_sommerville());
+    assert!(!SimplicialComplex::<usize>::solid(2).satisfies_dehn_sommerville());
+    assert!(!SimplicialComplex::dunce_hat().satisfies_dehn_sommerville());
+}
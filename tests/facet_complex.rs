@@ -0,0 +1,41 @@
+use tda::facet_complex::FacetComplex;
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplex::Simplex;
+
+#[test]
+fn keeps_maximal_simplices() {
+    let k = FacetComplex::new(vec![vec![0, 1].into(), vec![0, 1, 2].into(), vec![2, 3].into(), vec![3].into()]);
+    let facets: Vec<Simplex<i32>> = vec![vec![0, 1, 2].into(), vec![2, 3].into()];
+    assert_eq!(k.facets(), &facets.into_iter().collect());
+    assert_eq!(k.dim(), 2);
+    assert!(k.is_face_of_complex(&vec![0, 2].into()));
+    assert!(!k.is_face_of_complex(&vec![1, 3].into()));
+    assert_eq!(k.f_vector(), vec![4, 4, 1]);
+}
+
+#[test]
+fn round_trip() {
+    let k = SimplicialComplex::hollow(3);
+    let f = FacetComplex::from(&k);
+    assert_eq!(f.facets().len(), 4);
+    assert_eq!(f.to_complex(), k);
+    assert_eq!(f.f_vector(), vec![4, 6, 4]);
+    assert_eq!(f.dim_simplices(1), k.dim_simplices(1));
+    assert_eq!(SimplicialComplex::from(&FacetComplex::from(&SimplicialComplex::solid(2))), SimplicialComplex::solid(2));
+}
+
+#[test]
+fn empty() {
+    let k: FacetComplex<i32> = FacetComplex::new(vec![]);
+    assert_eq!(k.f_vector(), Vec::<usize>::new());
+    assert_eq!(k.to_complex(), SimplicialComplex::from(vec![]));
+}
+
+#[test]
+fn f_vector_matches_complex() {
+    for k in [SimplicialComplex::torus(), SimplicialComplex::projective_plane(), SimplicialComplex::dunce_hat()] {
+        assert_eq!(FacetComplex::from(&k).f_vector(), k.f_vector());
+    }
+    let k = FacetComplex::new(vec![vec![0, 1, 2, 3].into(), vec![2, 3, 4].into(), vec![0, 4].into(), vec![5].into()]);
+    assert_eq!(k.f_vector(), k.to_complex().f_vector());
+}
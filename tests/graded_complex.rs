@@ -0,0 +1,55 @@
+use tda::graded_complex::GradedComplex;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn buckets() {
+    let k = SimplicialComplex::torus();
+    let g = GradedComplex::from(&k);
+    assert_eq!(g.dim(), 2);
+    assert_eq!(g.f_vector(), vec![7, 21, 14]);
+    assert_eq!(g.count(1), 21);
+    assert_eq!(g.count(3), 0);
+    assert_eq!(g.len(), k.simplices.len());
+    assert_eq!(g.euler(), 0);
+    assert_eq!(g.to_complex(), k);
+    for i in 0..=2 {
+        let simplices: Vec<_> = k.dim_simplices(i).into_iter().collect();
+        assert_eq!(g.dim_simplices(i), &simplices[..]);
+        for (j, t) in simplices.iter().enumerate() {
+            assert_eq!(g.index(t), Some(j));
+        }
+    }
+    assert!(g.dim_simplices(3).is_empty());
+    assert!(!g.contains(&Simplex::from(vec![0, 1, 2, 3])));
+}
+
+#[test]
+fn boundaries_agree() {
+    for k in &[SimplicialComplex::projective_plane(), SimplicialComplex::<usize>::solid(3), SimplicialComplex::dunce_hat()] {
+        let g = GradedComplex::from(k);
+        for i in 0..=k.dim() + 1 {
+            assert_eq!(g.boundary::<i64>(i), k.boundary::<i64>(i));
+        }
+    }
+}
+
+#[test]
+fn betti_numbers() {
+    assert_eq!(SimplicialComplex::torus().betti_numbers(), vec![1, 2, 1]);
+    assert_eq!(SimplicialComplex::klein_bottle().betti_numbers(), vec![1, 1, 0]);
+    assert_eq!(SimplicialComplex::<usize>::sphere(3).betti_numbers(), vec![1, 0, 0, 1]);
+    let k = SimplicialComplex::mobius_strip();
+    let betti: Vec<usize> = (0..=k.dim()).map(|i| k.betti(i)).collect();
+    assert_eq!(k.betti_numbers(), betti);
+}
+
+#[test]
+fn empty_complex() {
+    let g = GradedComplex::from(&SimplicialComplex::<usize>::new(Default::default()));
+    assert!(g.is_empty());
+    assert_eq!(g.len(), 0);
+    assert!(g.f_vector().is_empty());
+    assert!(g.betti_numbers().is_empty());
+    assert_eq!(g.euler(), 0);
+}
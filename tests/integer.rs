@@ -0,0 +1,55 @@
+use tda::linear_algebra::integer::{invariant_factors, is_prime, rank, rank_mod_p};
+use tda::linear_algebra::coefficients::{Coefficients, Prime};
+use tda::simplicial_complex::SimplicialComplex;
+use nalgebra::{DMatrix, dmatrix};
+
+#[test]
+fn small_matrices() {
+    assert_eq!(invariant_factors(&dmatrix![2, 4; 6, 8]), vec![2, 4]);
+    assert_eq!(invariant_factors(&dmatrix![2, 0; 0, 3]), vec![1, 6]);
+    assert_eq!(invariant_factors(&dmatrix![1, 2, 3; 4, 5, 6; 7, 8, 9]), vec![1, 3]);
+    assert_eq!(invariant_factors(&DMatrix::<i64>::zeros(2, 3)), vec![]);
+    assert_eq!(rank(&dmatrix![1, 2, 3; 4, 5, 6; 7, 8, 9]), 2);
+}
+
+#[test]
+fn ranks_mod_p() {
+    let m = dmatrix![2, 0; 0, 3];
+    assert_eq!(rank_mod_p(&m, 2), 1);
+    assert_eq!(rank_mod_p(&m, 3), 1);
+    assert_eq!(rank_mod_p(&m, 5), 2);
+    assert_eq!(rank_mod_p(&dmatrix![-1, 1; 1, -1], 7), 1);
+    // Products of entries mod a large prime do not fit in an i64.
+    let p = 4_611_686_018_427_387_847;
+    assert_eq!(rank_mod_p(&dmatrix![p - 1, 2; 3, p - 6], p), 1);
+    assert_eq!(Coefficients::prime(2).unwrap().rank(&m), 1);
+    assert_eq!(Coefficients::Rationals.rank(&m), 2);
+}
+
+#[test]
+fn torsion_of_projective_plane() {
+    let k = SimplicialComplex::projective_plane();
+    let d2 = k.boundary::<i64>(2);
+    let factors = invariant_factors(&d2);
+    assert_eq!(factors.len(), 10);
+    assert_eq!(factors.last(), Some(&2));
+    assert!(factors[..9].iter().all(|&d| d == 1));
+}
+
+#[test]
+fn primes() {
+    let primes: Vec<i64> = (0..30).filter(|&n| is_prime(n)).collect();
+    assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert!(is_prime(4_611_686_018_427_387_847));
+    assert!(!is_prime(3_215_031_751));
+    assert_eq!(Prime::new(7).map(Prime::get), Some(7));
+    assert_eq!(Coefficients::prime(7), Prime::new(7).map(Coefficients::Prime));
+    assert_eq!(Coefficients::prime(4), None);
+    assert_eq!(Coefficients::prime(0), None);
+}
+
+#[test]
+#[should_panic]
+fn rank_mod_composite() {
+    rank_mod_p(&dmatrix![2, 0; 0, 2], 4);
+}
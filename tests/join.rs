@@ -0,0 +1,45 @@
+use tda::simplicial_complex::SimplicialComplex;
+use tda::join::JoinVertex::{Left, Right};
+
+#[test]
+fn cone_is_contractible() {
+    let k = SimplicialComplex::hollow(2).cone();
+    assert!(k.is_valid());
+    assert_eq!(k.dim(), 2);
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 0);
+    assert_eq!(k.betti(2), 0);
+    // The cone over a simplex is a simplex
+    let c = SimplicialComplex::solid(1).cone();
+    assert_eq!(c, SimplicialComplex::solid(2).map_vertices(|v| if v < 2 { Left(v) } else { Right(0) }));
+}
+
+#[test]
+fn suspension_of_circle_is_sphere() {
+    let k = SimplicialComplex::hollow(2).suspension();
+    assert!(k.is_valid());
+    assert_eq!(k.euler(), 2);
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 0);
+    assert_eq!(k.betti(2), 1);
+}
+
+#[test]
+fn join_of_two_point_sets_is_circle() {
+    let s0 = SimplicialComplex::hollow(1);
+    let k = s0.join(&s0);
+    assert_eq!(k.simplices.len(), 8);
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 1);
+}
+
+#[test]
+fn wedge_of_circles() {
+    let s1 = SimplicialComplex::hollow(2);
+    let k = s1.wedge(0, &s1, 2);
+    assert_eq!(k.vertices.len(), 5);
+    assert!(k.vertices.contains(&Left(0)));
+    assert!(!k.vertices.contains(&Right(2)));
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 2);
+}
@@ -0,0 +1,50 @@
+use tda::chain::Chain;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+use nalgebra::dvector;
+
+#[test]
+fn labels_match_boundary() {
+    let k = SimplicialComplex::torus();
+    for i in 0..=3 {
+        let b = k.labelled_boundary::<i64>(i);
+        assert_eq!(b.matrix(), &k.boundary::<i64>(i));
+        assert_eq!(b.columns().len(), b.matrix().ncols());
+        assert_eq!(b.rows().len(), b.matrix().nrows());
+        for (j, t) in b.columns().iter().enumerate() {
+            assert_eq!(b.column_index(t), Some(j));
+            for (r, f) in b.rows().iter().enumerate() {
+                assert_eq!(b.matrix()[(r, j)], t.boundary_coeff::<i64>(f));
+            }
+        }
+    }
+}
+
+#[test]
+fn vertices_label_the_zeroth_boundary() {
+    let b = SimplicialComplex::<usize>::solid(2).labelled_boundary::<i64>(0);
+    assert!(b.rows().is_empty());
+    assert_eq!(b.columns(), &[Simplex::from(vec![0]), Simplex::from(vec![1]), Simplex::from(vec![2])]);
+    assert_eq!(b.row_index(&Simplex::from(vec![0])), None);
+}
+
+#[test]
+fn kernel_vectors_are_cycles() {
+    let b = SimplicialComplex::<usize>::hollow(2).labelled_boundary::<i64>(1);
+    assert_eq!(b.columns(), &[Simplex::from(vec![0, 1]), Simplex::from(vec![0, 2]), Simplex::from(vec![1, 2])]);
+    let v = dvector![1, -1, 1];
+    assert!((b.matrix() * &v).iter().all(|&x| x == 0));
+    let cycle = b.column_chain(&v);
+    assert!(cycle.is_cycle());
+    assert_eq!(cycle, Chain::simplex(Simplex::from(vec![0, 1, 2])).boundary());
+    assert_eq!(b.column_vector(&cycle), v);
+}
+
+#[test]
+fn boundaries_agree_with_chains() {
+    let k = SimplicialComplex::projective_plane();
+    let b = k.labelled_boundary::<i64>(2);
+    let c: Chain<usize, i64> = vec![(Simplex::from(vec![0, 1, 2]), 2), (Simplex::from(vec![1, 3, 5]), -1)].into_iter().collect();
+    let image = b.row_chain(&(b.matrix() * b.column_vector(&c)));
+    assert_eq!(image, c.boundary());
+}
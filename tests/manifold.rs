@@ -0,0 +1,87 @@
+use tda::manifold::ManifoldError;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn closed_surfaces() {
+    for k in &[SimplicialComplex::torus(), SimplicialComplex::projective_plane(), SimplicialComplex::klein_bottle(), SimplicialComplex::<usize>::sphere(2)] {
+        assert!(k.is_pure());
+        assert!(k.is_pseudomanifold());
+        assert_eq!(k.check_manifold(), Ok(()));
+        assert!(k.boundary_subcomplex().simplices.is_empty());
+    }
+}
+
+#[test]
+fn surfaces_with_boundary() {
+    let k = SimplicialComplex::mobius_strip();
+    assert!(k.is_manifold());
+    let boundary = k.boundary_subcomplex();
+    assert_eq!(boundary.f_vector(), vec![5, 5]);
+    assert!(boundary.simplices.contains(&Simplex::from(vec![0, 2])));
+    assert!(boundary.is_manifold());
+    let disk = SimplicialComplex::<usize>::solid(2);
+    assert!(disk.is_manifold());
+    assert_eq!(disk.boundary_subcomplex(), SimplicialComplex::hollow(2));
+}
+
+#[test]
+fn three_manifolds() {
+    assert!(SimplicialComplex::<usize>::sphere(3).is_manifold());
+    let ball = SimplicialComplex::<usize>::solid(3);
+    assert!(ball.is_manifold());
+    assert_eq!(ball.boundary_subcomplex(), SimplicialComplex::sphere(2));
+    assert!(SimplicialComplex::lens_space(2).is_manifold());
+    // The suspension of a torus is a pseudomanifold, but the links of the suspension points are
+    // tori
+    let k = SimplicialComplex::torus().suspension();
+    assert!(k.is_pseudomanifold());
+    match k.check_manifold() {
+        Err(ManifoldError::BadLink(v)) => {
+            // Both suspension points
+            assert_eq!(v.len(), 2);
+            for x in v {
+                assert_eq!(k.link(&vec![x].into()).euler(), 0);
+            }
+        },
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn unsupported_dimension() {
+    let k = SimplicialComplex::<usize>::sphere(4);
+    assert!(k.is_pseudomanifold());
+    assert_eq!(k.check_manifold(), Err(ManifoldError::UnsupportedDimension(4)));
+}
+
+#[test]
+fn diagnostics() {
+    // A triangle with two dangling edges
+    let k = SimplicialComplex::closure(vec![Simplex::from(vec![0, 1, 2]), Simplex::from(vec![2, 3]), Simplex::from(vec![0, 4])]);
+    let lower = vec![Simplex::from(vec![0, 4]), Simplex::from(vec![2, 3])];
+    assert_eq!(k.check_pure(), Err(ManifoldError::NotPure(lower.clone())));
+    assert_eq!(k.check_pseudomanifold(), Err(ManifoldError::NotPure(lower)));
+    // Three triangles meeting along an edge
+    let k = SimplicialComplex::closure(vec![Simplex::from(vec![0, 1, 2]), Simplex::from(vec![0, 1, 3]), Simplex::from(vec![0, 1, 4])]);
+    assert_eq!(k.check_pseudomanifold(), Err(ManifoldError::Branching(vec![Simplex::from(vec![0, 1])])));
+    assert!(!k.is_manifold());
+    // Three triangles meeting at a vertex, two of them along an edge
+    let k = SimplicialComplex::closure(vec![Simplex::from(vec![0, 1, 2]), Simplex::from(vec![2, 3, 4]), Simplex::from(vec![2, 4, 5])]);
+    assert_eq!(k.check_strongly_connected(), Err(ManifoldError::NotStronglyConnected(vec![Simplex::from(vec![0, 1, 2]), Simplex::from(vec![2, 3, 4])])));
+    assert_eq!(k.check_manifold(), Err(ManifoldError::BadLink(vec![2])));
+    // The dunce hat has an edge in three triangles
+    let k = SimplicialComplex::dunce_hat();
+    assert!(matches!(k.check_pseudomanifold(), Err(ManifoldError::Branching(_))));
+}
+
+#[test]
+fn low_dimensions() {
+    let points = SimplicialComplex::<usize>::sphere(0);
+    assert!(points.is_manifold());
+    assert!(!points.is_strongly_connected());
+    assert!(SimplicialComplex::<usize>::sphere(1).is_manifold());
+    // A vertex of degree 3 is not a manifold point
+    let k = SimplicialComplex::from(vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![0], vec![1], vec![2], vec![3]]);
+    assert_eq!(k.check_manifold(), Err(ManifoldError::BadLink(vec![0])));
+}
@@ -0,0 +1,73 @@
+use tda::chain_complex::Homology;
+use tda::manifold::ManifoldError;
+use tda::orientation::OrientationError;
+use tda::simplex::Simplex;
+use tda::simplicial_complex::SimplicialComplex;
+use tda::linear_algebra::coefficients::Coefficients;
+
+fn assert_reversing_loop(k: &SimplicialComplex<usize>) {
+    match k.orient() {
+        Err(OrientationError::NonOrientable(l)) => {
+            assert!(l.len() >= 2);
+            // Consecutive simplices meet in a codimension 1 face, including the last and first,
+            // and carrying an orientation around the loop reverses it
+            let mut sign = 1;
+            for i in 0..l.len() {
+                let (s, t) = (&l[i], &l[(i + 1) % l.len()]);
+                let f: Simplex<usize> = s.vertices.intersection(&t.vertices).cloned().collect();
+                assert_eq!(f.dim() + 1, k.dim());
+                sign *= -s.boundary_coeff::<i64>(&f) * t.boundary_coeff::<i64>(&f);
+            }
+            assert_eq!(sign, -1);
+        },
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn orientable_closed_surfaces() {
+    for k in &[SimplicialComplex::torus(), SimplicialComplex::<usize>::sphere(2)] {
+        let o = k.orient().unwrap();
+        for s in k.dim_simplices(2) {
+            assert!(o.sign(&s).unwrap().abs() == 1);
+        }
+        let c = o.fundamental_class().unwrap();
+        assert!(c.is_cycle());
+        assert_eq!(c.support(), k.dim_simplices(2));
+        assert_eq!(k.homology(2, Coefficients::Integers), Homology { rank: 1, torsion: vec![] });
+    }
+}
+
+#[test]
+fn orientable_three_manifolds() {
+    for k in &[SimplicialComplex::<usize>::sphere(3), SimplicialComplex::lens_space(2)] {
+        let c = k.orient().unwrap().fundamental_class().unwrap();
+        assert!(c.is_cycle());
+        assert!(!c.is_boundary(k, Coefficients::Integers));
+    }
+}
+
+#[test]
+fn orientable_with_boundary() {
+    let k = SimplicialComplex::<usize>::solid(2);
+    let o = k.orient().unwrap();
+    assert_eq!(o.fundamental_class(), None);
+    assert!(!o.chain().is_cycle());
+    // The boundary of the orientation is a fundamental class of the boundary
+    let boundary = k.boundary_subcomplex();
+    assert_eq!(o.chain().boundary().support(), boundary.dim_simplices(1));
+}
+
+#[test]
+fn non_orientable() {
+    assert_reversing_loop(&SimplicialComplex::mobius_strip());
+    assert_reversing_loop(&SimplicialComplex::klein_bottle());
+    assert_reversing_loop(&SimplicialComplex::projective_plane());
+    assert!(!SimplicialComplex::klein_bottle().is_orientable());
+}
+
+#[test]
+fn not_a_pseudomanifold() {
+    let k = SimplicialComplex::closure(vec![Simplex::from(vec![0, 1, 2]), Simplex::from(vec![2, 3])]);
+    assert_eq!(k.orient(), Err(OrientationError::NotPseudomanifold(ManifoldError::NotPure(vec![vec![2, 3].into()]))));
+}
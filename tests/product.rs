@@ -0,0 +1,42 @@
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn square() {
+    let k = SimplicialComplex::solid(1).product(&SimplicialComplex::solid(1));
+    assert_eq!(k, SimplicialComplex::closure(vec![
+        vec![(0, 0), (1, 0), (1, 1)].into(),
+        vec![(0, 0), (0, 1), (1, 1)].into()]));
+}
+
+#[test]
+fn prism() {
+    // A triangle times an interval is triangulated by 3 tetrahedra
+    let k = SimplicialComplex::solid(2).product(&SimplicialComplex::solid(1));
+    assert_eq!(k.dim_simplices(3).len(), 3);
+    assert_eq!(k.dim_simplices(0).len(), 6);
+    assert_eq!(k.euler(), 1);
+}
+
+#[test]
+fn torus() {
+    let s1 = SimplicialComplex::hollow(2);
+    let k = s1.product(&s1);
+    assert!(k.is_valid());
+    assert_eq!(k.dim_simplices(0).len(), 9);
+    assert_eq!(k.dim_simplices(2).len(), 18);
+    assert_eq!(k.euler(), 0);
+    // Künneth: H_*(S^1 x S^1) = H_*(S^1) ⊗ H_*(S^1)
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 2);
+    assert_eq!(k.betti(2), 1);
+}
+
+#[test]
+fn circle_times_sphere() {
+    let k = SimplicialComplex::hollow(2).product(&SimplicialComplex::hollow(3));
+    assert_eq!(k.euler(), 0);
+    assert_eq!(k.betti(0), 1);
+    assert_eq!(k.betti(1), 1);
+    assert_eq!(k.betti(2), 1);
+    assert_eq!(k.betti(3), 1);
+}
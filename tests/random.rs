@@ -0,0 +1,87 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use tda::random::{linial_meshulam, random_clique_complex, random_vietoris_rips, random_cech, random_subcomplex};
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn linial_meshulam_skeleton() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let k = linial_meshulam(8, 2, 0.5, &mut rng);
+    assert!(k.is_valid());
+    assert_eq!(k.skeleton(1), SimplicialComplex::solid(7).skeleton(1));
+    assert!(k.dim_simplices(2).len() < 56);
+    assert_eq!(linial_meshulam(5, 2, 1., &mut rng), SimplicialComplex::solid(4).skeleton(2));
+    assert_eq!(linial_meshulam(5, 2, 0., &mut rng), SimplicialComplex::solid(4).skeleton(1));
+}
+
+#[test]
+fn seeded_is_deterministic() {
+    let a = linial_meshulam(8, 2, 0.3, &mut StdRng::seed_from_u64(42));
+    let b = linial_meshulam(8, 2, 0.3, &mut StdRng::seed_from_u64(42));
+    assert_eq!(a, b);
+    let a = random_clique_complex(10, 0.4, &mut StdRng::seed_from_u64(7));
+    let b = random_clique_complex(10, 0.4, &mut StdRng::seed_from_u64(7));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn clique_complex() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let k = random_clique_complex(10, 0.5, &mut rng);
+    assert!(k.is_valid());
+    assert_eq!(k.vertices.len(), 10);
+    // A simplex is in the complex iff all of its edges are
+    for s in &k.simplices {
+        let edges = s.vertices.iter()
+            .flat_map(|&i| s.vertices.iter().filter(move |&&j| i < j).map(move |&j| vec![i, j].into()));
+        assert!(edges.into_iter().all(|e| k.simplices.contains(&e)));
+    }
+    assert_eq!(random_clique_complex(5, 1., &mut rng), SimplicialComplex::solid(4));
+}
+
+#[test]
+fn geometric_complexes() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let vr = random_vietoris_rips(12, 2, 0.3, &mut rng);
+    assert!(vr.is_valid());
+    assert_eq!(vr.vertices.len(), 12);
+    // Every pair of points in the unit square is within sqrt(2)
+    assert_eq!(random_vietoris_rips(6, 2, 1.5, &mut rng), SimplicialComplex::solid(5));
+    let cech = random_cech(12, 2, 0.2, 2, &mut rng);
+    assert!(cech.is_valid());
+    assert!(cech.dim() <= 2);
+}
+
+#[test]
+fn cech_is_between_vietoris_rips_complexes() {
+    // Cech(r) has the same 1-skeleton as VR(2r), and contains VR(r)
+    let mut rng = StdRng::seed_from_u64(3);
+    let r = 0.25;
+    let cech = random_cech(10, 2, r, 3, &mut StdRng::seed_from_u64(3));
+    let vr2 = random_vietoris_rips(10, 2, 2. * r, &mut rng);
+    let vr1 = random_vietoris_rips(10, 2, r, &mut StdRng::seed_from_u64(3));
+    assert_eq!(cech.skeleton(1), vr2.skeleton(1));
+    assert!(vr1.skeleton(3).is_subcomplex(&cech));
+    assert!(cech.is_subcomplex(&vr2));
+}
+
+#[test]
+fn subcomplex_of_simplex() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let k = random_subcomplex(5, 0.7, &mut rng);
+    assert!(k.is_valid());
+    assert!(k.is_subcomplex(&SimplicialComplex::solid(5)));
+    assert_eq!(random_subcomplex(3, 1., &mut rng), SimplicialComplex::solid(3));
+    assert_eq!(random_subcomplex(3, 0., &mut rng), SimplicialComplex::from(vec![]));
+}
+
+#[test]
+fn no_vertices() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let empty = SimplicialComplex::from(vec![]);
+    assert_eq!(linial_meshulam(0, 2, 0.5, &mut rng), empty);
+    assert_eq!(random_clique_complex(0, 0.5, &mut rng), empty);
+    assert_eq!(random_vietoris_rips(0, 2, 0.5, &mut rng), empty);
+    assert_eq!(random_cech(0, 2, 0.5, 2, &mut rng), empty);
+}
@@ -0,0 +1,53 @@
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplex::Simplex;
+
+#[test]
+fn disk_relative_to_boundary() {
+    let k = SimplicialComplex::solid(2);
+    let l = SimplicialComplex::hollow(2);
+    assert_eq!(k.relative_boundary::<i32>(2, &l).shape(), (0, 1));
+    assert_eq!(k.relative_betti(0, &l), 0);
+    assert_eq!(k.relative_betti(1, &l), 0);
+    assert_eq!(k.relative_betti(2, &l), 1);
+}
+
+#[test]
+fn relative_to_empty_and_self() {
+    let k = SimplicialComplex::torus();
+    let empty = SimplicialComplex::from(vec![]);
+    for i in 0..=2 {
+        assert_eq!(k.relative_betti(i, &empty), k.betti(i));
+        assert_eq!(k.relative_betti(i, &k), 0);
+    }
+}
+
+#[test]
+fn relative_to_vertex_is_reduced() {
+    let k = SimplicialComplex::hollow(1).suspension().suspension();
+    let v = SimplicialComplex::new(vec![k.vertices.iter().next().map(|&v| Simplex::from(vec![v])).unwrap()]
+        .into_iter().collect());
+    for i in 0..=2 {
+        assert_eq!(k.relative_betti(i, &v), k.reduced_betti(i));
+    }
+}
+
+#[test]
+fn local_homology_of_surface() {
+    // H_*(K, K - st(v)) is the homology of a 2-disk relative to its boundary at any vertex v of
+    // a surface
+    let k = SimplicialComplex::torus();
+    let v: Simplex<usize> = vec![0].into();
+    let rest = SimplicialComplex::new(k.simplices.iter()
+        .filter(|s| !v.is_face(s))
+        .cloned()
+        .collect());
+    assert_eq!(k.relative_betti(0, &rest), 0);
+    assert_eq!(k.relative_betti(1, &rest), 0);
+    assert_eq!(k.relative_betti(2, &rest), 1);
+}
+
+#[test]
+#[should_panic]
+fn not_a_subcomplex() {
+    SimplicialComplex::solid(1).relative_betti(0, &SimplicialComplex::solid(2));
+}
@@ -0,0 +1,90 @@
+use tda::simplex_tree::SimplexTree;
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplex::Simplex;
+use tda::filtration::Filtration;
+
+#[test]
+fn insert_adds_faces() {
+    let mut t = SimplexTree::new();
+    t.insert(&Simplex::from(vec![0, 1, 2]));
+    assert_eq!(t.len(), 7);
+    assert_eq!(t.dim(), 2);
+    assert!(t.contains(&vec![0, 2].into()));
+    assert!(!t.contains(&vec![0, 3].into()));
+    assert_eq!((t.count(0), t.count(1), t.count(2), t.count(3)), (3, 3, 1, 0));
+    t.insert(&Simplex::from(vec![0, 1]));
+    assert_eq!(t.len(), 7);
+    assert_eq!(t.to_complex(), SimplicialComplex::solid(2));
+}
+
+#[test]
+fn from_complex() {
+    let k = SimplicialComplex::hollow(3);
+    let t = SimplexTree::from(&k);
+    assert_eq!(t.len(), k.simplices.len());
+    assert_eq!(t.dim_simplices(2), k.dim_simplices(2));
+    assert_eq!(t.to_complex(), k);
+}
+
+#[test]
+fn faces_and_cofaces() {
+    let t = SimplexTree::from(&SimplicialComplex::from(vec![
+        vec![0], vec![1], vec![2], vec![3],
+        vec![0, 1], vec![0, 2], vec![1, 2], vec![1, 3],
+        vec![0, 1, 2]]));
+    let faces: Vec<Simplex<i32>> = t.faces(&vec![0, 1, 2].into()).collect();
+    assert_eq!(faces, vec![vec![1, 2].into(), vec![0, 2].into(), vec![0, 1].into()]);
+    let cofaces: Vec<Simplex<i32>> = t.cofaces(&vec![1].into()).collect();
+    assert_eq!(cofaces, vec![vec![0, 1].into(), vec![0, 1, 2].into(), vec![1, 2].into(), vec![1, 3].into()]);
+    assert_eq!(t.cofaces(&vec![0, 1, 2].into()).count(), 0);
+    assert_eq!(t.faces(&vec![2, 3].into()).count(), 0);
+}
+
+#[test]
+fn filtration_values() {
+    let mut t = SimplexTree::new();
+    t.insert_with_filtration(&vec![0, 1].into(), 1.);
+    t.insert_with_filtration(&vec![0].into(), 0.);
+    t.insert_with_filtration(&vec![1, 2].into(), 2.);
+    assert_eq!(t.filtration(&vec![0].into()), Some(0.));
+    assert_eq!(t.filtration(&vec![1].into()), Some(1.));
+    assert_eq!(t.filtration(&vec![2].into()), Some(2.));
+    assert_eq!(t.filtration(&vec![0, 2].into()), None);
+    let f = t.to_filtration(&[0., 1., 2.]);
+    assert_eq!(f.len(), 3);
+    assert_eq!(f.complex(0), SimplicialComplex::from(vec![vec![0]]));
+    assert_eq!(f.complex(1), SimplicialComplex::solid(1));
+    assert_eq!(f.complex(2), SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![0, 1], vec![1, 2]]));
+}
+
+#[test]
+fn filtration_from_complexes() {
+    let complexes = vec![
+        SimplicialComplex::from(vec![vec![0], vec![1]]),
+        SimplicialComplex::solid(1),
+        SimplicialComplex::solid(2),
+    ];
+    let f = Filtration::new(complexes.clone());
+    assert_eq!(f.len(), 3);
+    assert_eq!(f.tree().len(), 7);
+    assert_eq!(f.tree().filtration(&vec![0, 1].into()), Some(1.));
+    for (i, k) in complexes.iter().enumerate() {
+        assert_eq!(&f.complex(i), k);
+    }
+}
+
+#[test]
+fn non_copy_vertices() {
+    let k = SimplicialComplex::closure(vec![Simplex::from(vec!["a".to_string(), "b".to_string()])]);
+    let t = SimplexTree::from(&k);
+    assert_eq!(t.len(), 3);
+    let f = t.to_filtration(&[0.]);
+    assert_eq!(f.complex(0).simplices.len(), 3);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn filtration_must_be_nested() {
+    Filtration::new(vec![SimplicialComplex::solid(1), SimplicialComplex::from(vec![vec![0]])]);
+}
@@ -58,3 +58,29 @@ fn hollow_2() {
     let k = SimplicialComplex::hollow(2);
     assert_eq!(k, SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![0, 1], vec![0, 2], vec![1, 2], ]));
 }
+
+#[test]
+fn try_new_valid() {
+    let k = SimplicialComplex::hollow(2);
+    assert_eq!(SimplicialComplex::try_new(k.simplices.clone()), Ok(k));
+}
+
+#[test]
+fn try_new_missing_faces() {
+    let simplices = vec![vec![0, 1, 2], vec![0, 1], vec![0], vec![1]].into_iter()
+        .map(|x| x.into())
+        .collect();
+    let err = SimplicialComplex::try_new(simplices).unwrap_err();
+    assert_eq!(err.missing, vec![vec![0, 2], vec![1, 2], vec![2]].into_iter().map(|x| x.into()).collect());
+    assert!(!SimplicialComplex::from(vec![vec![0, 1, 2]]).is_valid());
+}
+
+#[test]
+fn closure_of_maximal_simplices() {
+    let k = SimplicialComplex::closure(vec![vec![0, 1, 2].into()]);
+    assert_eq!(k, SimplicialComplex::solid(2));
+    let k = SimplicialComplex::closure(vec![vec![0, 1].into(), vec![1, 2].into(), vec![0, 2].into()]);
+    assert_eq!(k, SimplicialComplex::hollow(2));
+    assert!(k.is_valid());
+    assert_eq!(k.betti(1), 1);
+}
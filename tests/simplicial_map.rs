@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplicial_map::{SimplicialMap, SimplicialMapError, SimplicialSelfMap};
+use tda::linear_algebra::coefficients::Coefficients;
+use nalgebra::dmatrix;
+
+fn cycle(n: usize) -> SimplicialComplex<usize> {
+    SimplicialComplex::closure((0..n).map(|i| vec![i, (i + 1) % n].into()))
+}
+
+#[test]
+fn identity() {
+    let k = SimplicialComplex::torus();
+    let f = SimplicialMap::from_fn(k.clone(), k, |&v| v).unwrap();
+    let h1 = f.induced_map(1, Coefficients::Rationals).unwrap();
+    assert_eq!((h1.source, h1.target, h1.rank, h1.kernel, h1.cokernel), (2, 2, 2, 0, 0));
+    assert_eq!(f.induced_map(2, Coefficients::prime(2).unwrap()).unwrap().rank, 1);
+}
+
+#[test]
+fn chain_map_signs() {
+    // The reflection 0 <-> 1 of an edge reverses its orientation
+    let k = SimplicialComplex::solid(1);
+    let f = SimplicialMap::from_fn(k.clone(), k, |&v| 1 - v).unwrap();
+    assert_eq!(f.chain_map::<i32>(0), dmatrix![0, 1; 1, 0]);
+    assert_eq!(f.chain_map::<i32>(1), dmatrix![-1]);
+    // A collapsed edge is degenerate
+    let g = SimplicialMap::from_fn(SimplicialComplex::solid(1), SimplicialComplex::solid(0), |_| 0).unwrap();
+    assert_eq!(g.chain_map::<i32>(1).shape(), (0, 1));
+}
+
+#[test]
+fn chain_map_commutes_with_boundary() {
+    let f = SimplicialMap::from_fn(cycle(6), cycle(3), |&v| v % 3).unwrap();
+    let d = f.domain().boundary::<i32>(1);
+    let e = f.codomain().boundary::<i32>(1);
+    assert_eq!(e * f.chain_map::<i32>(1), f.chain_map::<i32>(0) * d);
+}
+
+#[test]
+fn double_cover_of_circle() {
+    // Wrapping a circle around another twice has degree 2, which is zero mod 2
+    let f = SimplicialMap::from_fn(cycle(6), cycle(3), |&v| v % 3).unwrap();
+    assert_eq!(f.induced_map(1, Coefficients::Rationals).unwrap().rank, 1);
+    assert_eq!(f.induced_map(1, Coefficients::prime(3).unwrap()).unwrap().rank, 1);
+    let h1 = f.induced_map(1, Coefficients::prime(2).unwrap()).unwrap();
+    assert_eq!((h1.rank, h1.kernel, h1.cokernel), (0, 1, 1));
+    assert_eq!(f.induced_map(0, Coefficients::prime(2).unwrap()).unwrap().rank, 1);
+}
+
+#[test]
+fn inclusion_of_boundary() {
+    let f = SimplicialMap::from_fn(SimplicialComplex::hollow(2), SimplicialComplex::solid(2), |&v| v).unwrap();
+    let h1 = f.induced_map(1, Coefficients::Rationals).unwrap();
+    assert_eq!((h1.source, h1.target, h1.rank), (1, 0, 0));
+}
+
+#[test]
+fn invalid_maps() {
+    let k = SimplicialComplex::solid(2);
+    let l = SimplicialComplex::hollow(2);
+    assert_eq!(SimplicialMap::from_fn(k.clone(), l.clone(), |&v| v),
+               Err(SimplicialMapError::NotSimplicial(vec![0, 1, 2].into())));
+    assert_eq!(SimplicialMap::from_fn(k.clone(), l.clone(), |&v| v + 1),
+               Err(SimplicialMapError::BadVertex(2)));
+    let map: BTreeMap<usize, usize> = vec![(0, 0), (1, 1)].into_iter().collect();
+    assert_eq!(SimplicialMap::new(k, l, map), Err(SimplicialMapError::MissingVertex(2)));
+}
+
+#[test]
+fn lefschetz_of_identity_is_euler_characteristic() {
+    for k in [SimplicialComplex::torus(), SimplicialComplex::sphere(2), SimplicialComplex::projective_plane()] {
+        let f = SimplicialSelfMap::from_fn(k.clone(), |&v| v).unwrap();
+        assert_eq!(f.lefschetz_number(), k.euler() as i64);
+    }
+}
+
+#[test]
+fn lefschetz_of_contractible_complex() {
+    // Every self-map of a contractible complex has Lefschetz number 1
+    let k = SimplicialComplex::solid(3);
+    let f = SimplicialSelfMap::from_fn(k, |&v| (v + 1) % 4).unwrap();
+    assert_eq!(f.lefschetz_number(), 1);
+    assert!(f.has_fixed_point());
+    assert_eq!(f.invariant_simplices().cloned().collect::<Vec<_>>(), vec![vec![0, 1, 2, 3].into()]);
+}
+
+#[test]
+fn rotation_of_circle() {
+    // A rotation has no fixed points, and Lefschetz number 0
+    let k = cycle(5);
+    let f = SimplicialSelfMap::from_fn(k, |&v| (v + 1) % 5).unwrap();
+    assert_eq!(f.lefschetz_number(), 0);
+    assert!(!f.has_fixed_point());
+}
+
+#[test]
+fn reflection_of_circle() {
+    // A reflection acts by -1 on H_1, so has Lefschetz number 2, and fixes two points
+    let k = cycle(4);
+    let f = SimplicialSelfMap::from_fn(k, |&v| (4 - v) % 4).unwrap();
+    assert_eq!(f.lefschetz_number(), 2);
+    assert_eq!(f.invariant_simplices().cloned().collect::<Vec<_>>(), vec![vec![0].into(), vec![2].into()]);
+}
+
+#[test]
+fn antipodal_map_of_sphere() {
+    // The antipodal map of the octahedron has degree -1, so Lefschetz number 1 + (-1) = 0
+    let s0 = SimplicialComplex::hollow(1);
+    let k = s0.join(&s0).join(&s0);
+    let f = SimplicialSelfMap::from_fn(k, |v| {
+        use tda::join::JoinVertex::{Left, Right};
+        let flip = |x: &usize| 1 - *x;
+        match v {
+            Left(Left(x)) => Left(Left(flip(x))),
+            Left(Right(x)) => Left(Right(flip(x))),
+            Right(x) => Right(flip(x)),
+        }
+    }).unwrap();
+    assert_eq!(f.lefschetz_number(), 0);
+    assert!(!f.has_fixed_point());
+}
+
+#[test]
+fn induced_map_over_integers() {
+    let k = SimplicialComplex::projective_plane();
+    let f = SimplicialMap::from_fn(k.clone(), k, |&v| v).unwrap();
+    assert_eq!(f.induced_map(1, Coefficients::Integers), None);
+}
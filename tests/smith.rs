@@ -1,5 +1,5 @@
 use tda::linear_algebra::smith::Smith;
-use nalgebra::{DMatrix, dmatrix};
+use nalgebra::dmatrix;
 
 #[test]
 fn identity() {
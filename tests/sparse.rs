@@ -0,0 +1,26 @@
+use tda::linear_algebra::sparse::SparseMatrix;
+use nalgebra::dmatrix;
+
+#[test]
+fn dense_round_trip() {
+    let m = dmatrix![1, 0, -2; 0, 0, 3];
+    let s = SparseMatrix::from(&m);
+    assert_eq!((s.nrows(), s.ncols()), (2, 3));
+    assert_eq!(s.column(1), &[]);
+    assert_eq!(s.column(2), &[(0, -2), (1, 3)]);
+    assert_eq!(s.to_dense(), m);
+    // Entries are merged and zeros dropped, in any order
+    assert_eq!(SparseMatrix::new(2, vec![vec![(0, 1)], vec![(1, 2), (1, -2)], vec![(1, 3), (0, -2)]]), s);
+}
+
+#[test]
+fn products() {
+    let a = dmatrix![1, 2; 0, 1; 3, 0];
+    let b = dmatrix![2, 0, 1; -1, 1, 0];
+    let p = SparseMatrix::from(&a).checked_mul(&SparseMatrix::from(&b)).unwrap();
+    assert_eq!(p.to_dense(), &a * &b);
+    assert_eq!((-&p).to_dense(), -(&a * &b));
+    let big = SparseMatrix::from(dmatrix![i64::MAX]);
+    assert_eq!(big.checked_mul(&SparseMatrix::from(dmatrix![2])), None);
+    assert!(SparseMatrix::from(dmatrix![1, 1]).checked_mul(&SparseMatrix::from(dmatrix![1; -1])).unwrap().is_zero());
+}
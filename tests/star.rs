@@ -0,0 +1,36 @@
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplex::Simplex;
+
+#[test]
+fn star_of_vertex() {
+    let k = SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![3],
+        vec![0, 1], vec![0, 2], vec![1, 2], vec![2, 3],
+        vec![0, 1, 2]]);
+    let s: Simplex<i32> = vec![2].into();
+    assert_eq!(k.star(&s), vec![vec![2], vec![0, 2], vec![1, 2], vec![2, 3], vec![0, 1, 2]]
+        .into_iter().map(|x| x.into()).collect());
+    assert_eq!(k.closed_star(&s), k);
+    assert_eq!(k.link(&s), SimplicialComplex::from(vec![vec![0], vec![1], vec![3], vec![0, 1]]));
+}
+
+#[test]
+fn link_of_edge() {
+    let k = SimplicialComplex::hollow(3);
+    let s: Simplex<usize> = vec![0, 1].into();
+    assert_eq!(k.link(&s), SimplicialComplex::from(vec![vec![2], vec![3]]));
+    assert_eq!(k.closed_star(&s), SimplicialComplex::closure(vec![vec![0, 1, 2].into(), vec![0, 1, 3].into()]));
+}
+
+#[test]
+fn link_of_vertex_in_sphere_is_sphere() {
+    let k = SimplicialComplex::hollow(3);
+    let l = k.link(&vec![3].into());
+    assert_eq!(l, SimplicialComplex::hollow(2));
+    assert_eq!(l.betti(1), 1);
+}
+
+#[test]
+fn link_of_maximal_simplex_is_empty() {
+    let k = SimplicialComplex::solid(2);
+    assert_eq!(k.link(&vec![0, 1, 2].into()), SimplicialComplex::from(vec![]));
+}
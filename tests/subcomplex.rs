@@ -0,0 +1,40 @@
+use tda::simplicial_complex::SimplicialComplex;
+
+#[test]
+fn skeleton() {
+    let k = SimplicialComplex::solid(3);
+    assert_eq!(k.skeleton(2), SimplicialComplex::hollow(3));
+    assert_eq!(k.skeleton(0), SimplicialComplex::from(vec![vec![0], vec![1], vec![2], vec![3]]));
+    assert_eq!(k.skeleton(5), k);
+}
+
+#[test]
+fn subcomplex() {
+    let k = SimplicialComplex::solid(2);
+    assert!(SimplicialComplex::hollow(2).is_subcomplex(&k));
+    assert!(!k.is_subcomplex(&SimplicialComplex::hollow(2)));
+    assert!(k.is_subcomplex(&k));
+}
+
+#[test]
+fn union_and_intersection() {
+    // Two triangles sharing the edge [1, 2]
+    let a = SimplicialComplex::closure(vec![vec![0, 1, 2].into()]);
+    let b = SimplicialComplex::closure(vec![vec![1, 2, 3].into()]);
+    let u = a.union(&b);
+    assert_eq!(u, SimplicialComplex::closure(vec![vec![0, 1, 2].into(), vec![1, 2, 3].into()]));
+    assert!(u.is_valid());
+    assert_eq!(a.intersection(&b), SimplicialComplex::closure(vec![vec![1, 2].into()]));
+    // The Euler characteristic satisfies inclusion-exclusion
+    assert_eq!(u.euler(), a.euler() + b.euler() - a.intersection(&b).euler());
+}
+
+#[test]
+fn difference() {
+    let a = SimplicialComplex::closure(vec![vec![0, 1, 2].into(), vec![2, 3].into()]);
+    let b = SimplicialComplex::closure(vec![vec![0, 1, 2].into()]);
+    let d = a.difference(&b);
+    assert_eq!(d, SimplicialComplex::closure(vec![vec![2, 3].into()]));
+    assert!(d.is_valid());
+    assert_eq!(SimplicialComplex::solid(2).difference(&SimplicialComplex::hollow(2)), SimplicialComplex::solid(2));
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use tda::simplicial_complex::SimplicialComplex;
+use tda::simplex::Simplex;
+
+#[test]
+fn order_complex_of_chain() {
+    let elements: BTreeSet<i32> = (0..4).collect();
+    let k = SimplicialComplex::order_complex(&elements, |a, b| a < b);
+    assert_eq!(k, SimplicialComplex::closure(vec![vec![0, 1, 2, 3].into()]));
+}
+
+#[test]
+fn order_complex_of_antichain() {
+    let elements: BTreeSet<i32> = (0..3).collect();
+    let k = SimplicialComplex::order_complex(&elements, |_, _| false);
+    assert_eq!(k, SimplicialComplex::from(vec![vec![0], vec![1], vec![2]]));
+}
+
+#[test]
+fn order_complex_of_divisibility() {
+    // The divisors of 12 other than 1 and 12: 2 | 4, 2 | 6, 3 | 6
+    let elements: BTreeSet<i32> = vec![2, 3, 4, 6].into_iter().collect();
+    let k = SimplicialComplex::order_complex(&elements, |a, b| a != b && b % a == 0);
+    assert_eq!(k, SimplicialComplex::closure(vec![vec![2, 4].into(), vec![2, 6].into(), vec![3, 6].into()]));
+}
+
+#[test]
+fn subdivide_triangle() {
+    let k = SimplicialComplex::solid(2).barycentric_subdivision();
+    assert!(k.is_valid());
+    assert_eq!(k.dim_simplices(0).len(), 7);
+    assert_eq!(k.dim_simplices(1).len(), 12);
+    assert_eq!(k.dim_simplices(2).len(), 6);
+    let s: Simplex<usize> = vec![0, 1, 2].into();
+    assert_eq!(k.link(&vec![s].into()).betti(1), 1);
+}
+
+#[test]
+fn subdivision_preserves_homology() {
+    let k = SimplicialComplex::hollow(3);
+    let sd = k.barycentric_subdivision();
+    assert_eq!(sd.dim_simplices(2).len(), 24);
+    assert_eq!(sd.euler(), k.euler());
+    assert_eq!(sd.betti(0), 1);
+    assert_eq!(sd.betti(1), 0);
+    assert_eq!(sd.betti(2), 1);
+}
@@ -0,0 +1,82 @@
+use tda::chain_complex::{ChainComplex, Homology};
+use tda::simplicial_complex::SimplicialComplex;
+use tda::linear_algebra::coefficients::Coefficients;
+
+mod common;
+
+use common::cellular_projective_plane;
+
+fn cyclic(torsion: Vec<i64>) -> Homology {
+    Homology { rank: 0, torsion }
+}
+
+#[test]
+fn tensor_ranks() {
+    let c = cellular_projective_plane();
+    let t = c.tensor(&c);
+    assert_eq!(t.dim(), 4);
+    assert_eq!((0..=4).map(|n| t.rank(n)).collect::<Vec<_>>(), vec![1, 2, 3, 2, 1]);
+}
+
+#[test]
+fn kunneth_for_projective_planes() {
+    let c = cellular_projective_plane();
+    let t = c.tensor(&c);
+    // H_1 = Z/2, H_2 = Z/2 ⊗ Z/2, H_3 = Tor(Z/2, Z/2)
+    let expected = [
+        Homology { rank: 1, torsion: vec![] },
+        cyclic(vec![2, 2]),
+        cyclic(vec![2]),
+        cyclic(vec![2]),
+        cyclic(vec![]),
+    ];
+    for (n, h) in expected.iter().enumerate() {
+        assert_eq!(&t.homology(n, Coefficients::Integers), h);
+        assert_eq!(&c.kunneth(&c, n), h);
+    }
+}
+
+#[test]
+fn kunneth_for_simplicial_complexes() {
+    let k = ChainComplex::from(&SimplicialComplex::projective_plane());
+    let l = ChainComplex::from(&SimplicialComplex::<usize>::hollow(2));
+    let t = k.tensor(&l);
+    for n in 0..=t.dim() {
+        assert_eq!(t.homology(n, Coefficients::Integers), k.kunneth(&l, n));
+    }
+}
+
+#[test]
+fn homology_arithmetic() {
+    let a = Homology { rank: 1, torsion: vec![2] };
+    let b = Homology { rank: 0, torsion: vec![3, 6] };
+    // Z/2 ⊕ Z/3 = Z/6
+    assert_eq!(a.direct_sum(&b), Homology { rank: 1, torsion: vec![6, 6] });
+    assert_eq!(a.tensor(&b), cyclic(vec![6, 6]));
+    assert_eq!(b.tensor(&b), cyclic(vec![3, 3, 3, 6]));
+    assert_eq!(a.tor(&b), cyclic(vec![2]));
+    assert_eq!(b.tor(&b), cyclic(vec![3, 3, 3, 6]));
+}
+
+#[test]
+fn eilenberg_zilber_for_torus() {
+    let circle = SimplicialComplex::<usize>::hollow(2);
+    let f = circle.eilenberg_zilber(&circle);
+    assert_eq!(f.target(), &ChainComplex::from(&circle.product(&circle)));
+    for n in 0..=2 {
+        let h = f.induced_map(n, Coefficients::Rationals).unwrap();
+        assert_eq!(h.source, h.target);
+        assert_eq!(h.rank, h.source);
+    }
+}
+
+#[test]
+fn eilenberg_zilber_with_torsion() {
+    let k = SimplicialComplex::projective_plane();
+    let l = SimplicialComplex::<usize>::solid(1);
+    let f = k.eilenberg_zilber(&l);
+    for n in 0..=3 {
+        let h = f.induced_map(n, Coefficients::prime(2).unwrap()).unwrap();
+        assert_eq!((h.kernel, h.cokernel), (0, 0));
+    }
+}
@@ -0,0 +1,71 @@
+use tda::chain_complex::Homology;
+use tda::linear_algebra::coefficients::Coefficients;
+use tda::simplicial_complex::SimplicialComplex;
+
+fn bettis(k: &SimplicialComplex<usize>) -> Vec<usize> {
+    (0..=k.dim()).map(|i| k.betti(i)).collect()
+}
+
+#[test]
+fn sphere() {
+    let k = SimplicialComplex::sphere(2);
+    assert_eq!(k.vertices.len(), 4);
+    assert_eq!(bettis(&k), vec![1, 0, 1]);
+    assert_eq!(bettis(&SimplicialComplex::sphere(0)), vec![2]);
+}
+
+#[test]
+fn torus() {
+    let k = SimplicialComplex::torus();
+    assert!(k.is_valid());
+    assert_eq!(k.vertices.len(), 7);
+    assert_eq!(k.dim_simplices(1).len(), 21);
+    assert_eq!(k.euler(), 0);
+    assert_eq!(bettis(&k), vec![1, 2, 1]);
+}
+
+#[test]
+fn klein_bottle() {
+    let k = SimplicialComplex::klein_bottle();
+    assert_eq!(k.vertices.len(), 8);
+    assert_eq!(k.euler(), 0);
+    // Over the rationals, the torsion in H_1 is invisible and H_2 vanishes
+    assert_eq!(bettis(&k), vec![1, 1, 0]);
+}
+
+#[test]
+fn projective_plane() {
+    let k = SimplicialComplex::projective_plane();
+    assert_eq!(k.vertices.len(), 6);
+    assert_eq!(k.dim_simplices(1).len(), 15);
+    assert_eq!(k.euler(), 1);
+    assert_eq!(bettis(&k), vec![1, 0, 0]);
+}
+
+#[test]
+fn mobius_strip() {
+    let k = SimplicialComplex::mobius_strip();
+    assert_eq!(k.euler(), 0);
+    assert_eq!(bettis(&k), vec![1, 1, 0]);
+}
+
+#[test]
+fn dunce_hat() {
+    let k = SimplicialComplex::dunce_hat();
+    assert_eq!(k.vertices.len(), 8);
+    assert_eq!(k.dim_simplices(2).len(), 17);
+    assert_eq!(k.euler(), 1);
+    assert_eq!(bettis(&k), vec![1, 0, 0]);
+}
+
+#[test]
+fn lens_space() {
+    let k = SimplicialComplex::lens_space(2);
+    assert!(k.is_valid());
+    assert_eq!(k.dim(), 3);
+    assert_eq!(k.euler(), 0);
+    // Every triangle of a closed 3-manifold is a face of exactly two tetrahedra
+    assert_eq!(k.dim_simplices(2).len(), 2 * k.dim_simplices(3).len());
+    assert_eq!(k.homology(1, Coefficients::Integers), Homology { rank: 0, torsion: vec![2] });
+    assert_eq!(k.homology(2, Coefficients::Integers), Homology { rank: 0, torsion: vec![] });
+}
@@ -1,4 +1,4 @@
-use tda::vietoris_rips::{vietoris_rips_simplicial_complex, vietoris_rips_simplicial_complex_step};
+use tda::vietoris_rips::{vietoris_rips_simplicial_complex, vietoris_rips_simplicial_complex_step, vietoris_rips_skeleton};
 use tda::simplicial_complex::SimplicialComplex;
 
 use nalgebra::dmatrix;
@@ -43,4 +43,6 @@ fn four_points() {
     assert_eq!(k1, vietoris_rips_simplicial_complex_step(&dist, 1., k0.clone()));
     assert_eq!(k_sqrt2, vietoris_rips_simplicial_complex_step(&dist, SQRT_2, k0));
     assert_eq!(k_sqrt2, vietoris_rips_simplicial_complex_step(&dist, SQRT_2, k1));
+    assert_eq!(vietoris_rips_skeleton(&dist, SQRT_2, 1), SimplicialComplex::solid(3).skeleton(1));
+    assert_eq!(vietoris_rips_skeleton(&dist, SQRT_2, 5), k_sqrt2);
 }